@@ -0,0 +1,158 @@
+use std::fmt::Write;
+use std::process::ExitCode;
+
+use aoc_common::{account_id, resolve_session, Cache, CacheEntry, CacheKey};
+use clap::{Args, Subcommand};
+
+use crate::registry;
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// List cached inputs with their size and hash
+    List(Filter),
+    /// Print a cached input
+    Show(ShowArgs),
+    /// Remove cached inputs, every one unless filtered
+    Clear(Filter),
+}
+
+#[derive(Args)]
+pub struct Filter {
+    /// Only inputs for this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only inputs for this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Only inputs for this account, as shown by `aoc cache list`
+    #[arg(long)]
+    account: Option<String>,
+}
+
+impl Filter {
+    fn matches(&self, key: &CacheKey) -> bool {
+        self.year.is_none_or(|year| year == key.year)
+            && self.day.is_none_or(|day| day == key.day)
+            && self
+                .account
+                .as_ref()
+                .is_none_or(|account| *account == key.account)
+    }
+}
+
+#[derive(Args)]
+pub struct ShowArgs {
+    /// Day of the input
+    day: u8,
+    /// Year of the input, defaults to the latest one with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Account to show, instead of the one behind the session cookie
+    #[arg(long, conflicts_with = "session")]
+    account: Option<String>,
+    /// Session cookie picking the account, overriding AOC_SESSION and the config file
+    #[arg(long)]
+    session: Option<String>,
+}
+
+pub fn run(command: CacheCommand) -> ExitCode {
+    let cache = Cache::open();
+    let result = match command {
+        CacheCommand::List(filter) => cache.entries().map(|entries| {
+            let entries: Vec<_> = entries
+                .into_iter()
+                .filter(|entry| filter.matches(&entry.key))
+                .collect();
+            print!("{}", render(&entries));
+        }),
+        CacheCommand::Show(args) => {
+            let account = match args.account {
+                Some(account) => Ok(account),
+                None => resolve_session(args.session.as_deref()).map(|s| account_id(&s)),
+            };
+            account
+                .map(|account| {
+                    CacheKey::new(
+                        args.year.unwrap_or_else(registry::latest_year),
+                        args.day,
+                        &account,
+                    )
+                })
+                .and_then(|key| match cache.get(&key)? {
+                    Some(text) => {
+                        println!("{text}");
+                        Ok(())
+                    }
+                    None => Err(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("nothing cached at {}", cache.path(&key).display()),
+                    )),
+                })
+        }
+        CacheCommand::Clear(filter) => cache
+            .clear(|key| filter.matches(key))
+            .map(|removed| println!("removed {removed} cached inputs")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}: {err}", cache.dir().display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Renders one line per cached input.
+fn render(entries: &[CacheEntry]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<12}  {:>4}  {:>3}  {:>6}  {:<12}  Status",
+        "Account", "Year", "Day", "Bytes", "SHA-256"
+    )
+    .unwrap();
+    for entry in entries {
+        let hash = entry.hash.as_deref().unwrap_or("-");
+        writeln!(
+            out,
+            "{:<12}  {:>4}  {:>3}  {:>6}  {:<12}  {}",
+            entry.key.account,
+            entry.key.year,
+            entry.key.day,
+            entry.len,
+            &hash[..hash.len().min(12)],
+            if entry.intact { "ok" } else { "CORRUPT" }
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render() {
+        let entry = |day, hash: Option<&str>, intact| CacheEntry {
+            key: CacheKey::new(2022, day, "3f2a91c0d4b7"),
+            path: PathBuf::from("day1.txt"),
+            len: 10422,
+            hash: hash.map(String::from),
+            intact,
+        };
+        let out = render(&[
+            entry(1, Some("a1b2c3d4e5f60718293a"), true),
+            entry(12, None, false),
+        ]);
+
+        assert_eq!(
+            out,
+            "Account       Year  Day   Bytes  SHA-256       Status\n\
+             3f2a91c0d4b7  2022    1   10422  a1b2c3d4e5f6  ok\n\
+             3f2a91c0d4b7  2022   12   10422  -             CORRUPT\n"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+//! The registry of solutions, shared by the `aoc` binary and the benches.
+
+pub mod registry;
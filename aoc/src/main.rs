@@ -0,0 +1,326 @@
+use std::process::ExitCode;
+
+use aoc::registry;
+use aoc_common::{
+    answers_path, resolve_session, submissions_path, submit, Answers, Entry, Feedback, InputSource,
+    Part, Report, Submissions, Verdict,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+mod cache;
+mod records;
+mod scaffold;
+mod score;
+mod scrape;
+mod table;
+mod variants;
+mod watch;
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or a whole year with --all
+    Run(RunArgs),
+    /// Submit an answer, solving the day first unless one is given
+    Submit(SubmitArgs),
+    /// Inspect or clear downloaded inputs
+    #[command(subcommand)]
+    Cache(cache::CacheCommand),
+    /// Extract the example input and answers from saved puzzle pages
+    Scrape(scrape::ScrapeArgs),
+    /// Create a crate for a day, or add a variant to one, and register it
+    New(scaffold::NewArgs),
+    /// Re-run a day's samples and real input whenever its files change
+    Watch(watch::WatchArgs),
+    /// Score a 2022 day 2 strategy guide under the rules in a TOML file
+    Score(score::ScoreArgs),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run, every registered solution for it is used
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Run every day of the year
+    #[arg(long)]
+    all: bool,
+    /// Event year, defaults to the latest one with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only solve the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Only run the named variant, e.g. `nom`, instead of cross-checking all
+    /// of a day's variants
+    #[arg(long)]
+    variant: Option<String>,
+    /// Solve this many times and report the fastest, to compare variants
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// Read the input from this file, or `-` for stdin, instead of inputs/YEAR/dayN.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+    /// Download inputs that are missing locally
+    #[arg(long)]
+    fetch: bool,
+    /// Session cookie used with --fetch, overriding AOC_SESSION and the config file
+    #[arg(long)]
+    session: Option<String>,
+    /// Save answers missing from answers.toml into it
+    #[arg(long)]
+    record: bool,
+    /// Output format for answers and timings
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit for
+    day: u8,
+    /// Part to submit for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to send, instead of the one from the day's first solution
+    answer: Option<String>,
+    /// Event year, defaults to the latest one with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Read the input from this file, or `-` for stdin, instead of inputs/YEAR/dayN.txt
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+    /// Download the input if it is missing locally
+    #[arg(long)]
+    fetch: bool,
+    /// Session cookie, overriding AOC_SESSION and the config file
+    #[arg(long)]
+    session: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Submit(args) => submit_answer(args),
+        Command::Cache(command) => cache::run(command),
+        Command::Scrape(args) => scrape::run(args),
+        Command::New(args) => scaffold::run(args),
+        Command::Watch(args) => watch::run(args),
+        Command::Score(args) => score::run(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let entries: Vec<Entry> = registry::solutions()
+        .into_iter()
+        .filter(|entry| entry.year == year && (args.all || Some(entry.day) == args.day))
+        .collect();
+
+    if entries.is_empty() {
+        match args.day {
+            Some(day) => eprintln!("no solution registered for {year} day {day}"),
+            None => eprintln!("no solutions registered for {year}"),
+        }
+        return ExitCode::FAILURE;
+    }
+
+    let (entries, others): (Vec<Entry>, Vec<Entry>) = entries.into_iter().partition(|entry| {
+        args.variant
+            .as_deref()
+            .is_none_or(|variant| variant == entry.variant)
+    });
+    if entries.is_empty() {
+        let mut known: Vec<&str> = others.iter().map(|entry| entry.variant).collect();
+        known.sort_unstable();
+        known.dedup();
+        eprintln!(
+            "no variant '{}' registered, try one of: {}",
+            args.variant.unwrap_or_default(),
+            known.join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let parts: Vec<Part> = match args.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let source = InputSource::from_arg(args.input.as_deref(), args.fetch, args.session.as_deref());
+    let mut input: Option<(u8, String)> = None;
+    let mut results: Vec<(Entry, Report)> = vec![];
+    let mut failed = false;
+    for entry in entries {
+        if input.as_ref().map(|(day, _)| *day) != Some(entry.day) {
+            match source.read(entry.year, entry.day) {
+                Ok(text) => input = Some((entry.day, text)),
+                Err(err) => {
+                    eprintln!("day {}: {err}", entry.day);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        let (_, text) = input.as_ref().unwrap();
+        let mut fastest: Option<Report> = None;
+        for _ in 0..args.repeat {
+            match (entry.solve)(text, &parts) {
+                Ok(report) => {
+                    if fastest.as_ref().is_none_or(|f| report.total() < f.total()) {
+                        fastest = Some(report);
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    failed = true;
+                    fastest = None;
+                    break;
+                }
+            }
+        }
+        if let Some(report) = fastest {
+            results.push((entry, report));
+        }
+    }
+
+    for message in variants::disagreements(&results) {
+        eprintln!("{message}");
+        failed = true;
+    }
+
+    let path = answers_path();
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.format {
+        Format::Text => print!(
+            "{}{}",
+            table::render(&results, &answers),
+            variants::compare(&results)
+        ),
+        Format::Json => print!(
+            "{}",
+            records::to_json(&records::collect(&results, &answers))
+        ),
+        Format::Csv => print!("{}", records::to_csv(&records::collect(&results, &answers))),
+    }
+
+    for (entry, report) in &results {
+        for part in &report.parts {
+            match answers.check(entry.year, entry.day, part.part, &part.answer) {
+                Verdict::Fail { .. } => failed = true,
+                Verdict::New if args.record => {
+                    answers.record(entry.year, entry.day, part.part, &part.answer);
+                }
+                _ => {}
+            }
+        }
+    }
+    if args.record {
+        if let Err(err) = answers.save(&path) {
+            eprintln!("{}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn submit_answer(args: SubmitArgs) -> ExitCode {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let year = args.year.unwrap_or_else(registry::latest_year);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let Some(entry) = registry::solutions()
+                .into_iter()
+                .find(|entry| (entry.year, entry.day) == (year, args.day))
+            else {
+                eprintln!("no solution registered for {year} day {}", args.day);
+                return ExitCode::FAILURE;
+            };
+            let source =
+                InputSource::from_arg(args.input.as_deref(), args.fetch, args.session.as_deref());
+            let report = source
+                .read(entry.year, entry.day)
+                .map_err(|err| format!("day {}: {err}", entry.day))
+                .and_then(|input| (entry.solve)(&input, &[part]).map_err(|err| err.to_string()));
+            match report {
+                Ok(mut report) => report.parts.remove(0).answer,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let session = match resolve_session(args.session.as_deref()) {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let log_path = submissions_path();
+    let mut log = match Submissions::load(&log_path) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let feedback = submit(year, args.day, part, &answer, &session, &mut log);
+    if let Err(err) = log.save(&log_path) {
+        eprintln!("{}: {err}", log_path.display());
+        return ExitCode::FAILURE;
+    }
+    let feedback = match feedback {
+        Ok(feedback) => feedback,
+        Err(err) => {
+            eprintln!("{year} day {} part {}: {err}", args.day, args.part);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Day {} Part {part}: {answer} is {feedback}", args.day);
+
+    if feedback != Feedback::Correct {
+        return ExitCode::FAILURE;
+    }
+    let path = answers_path();
+    let saved = Answers::load(&path).and_then(|mut answers| {
+        answers.record(year, args.day, part, &answer);
+        answers.save(&path)
+    });
+    if let Err(err) = saved {
+        eprintln!("{}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,123 @@
+use serde::Serialize;
+
+use aoc_common::{Answers, Entry, Report, Verdict};
+
+/// One solved part, flattened for machine-readable output.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static str,
+    pub variant: &'static str,
+    pub part: u8,
+    pub answer: String,
+    pub parse_us: u128,
+    pub solve_us: u128,
+    /// `PASS`, `FAIL` or `NEW` against answers.toml.
+    pub check: &'static str,
+    /// Recorded answer, when there is one.
+    pub expected: Option<String>,
+}
+
+pub fn collect(results: &[(Entry, Report)], answers: &Answers) -> Vec<Record> {
+    results
+        .iter()
+        .flat_map(|(entry, report)| {
+            report.parts.iter().map(|part| {
+                let verdict = answers.check(entry.year, entry.day, part.part, &part.answer);
+                Record {
+                    year: entry.year,
+                    day: entry.day,
+                    solution: entry.name,
+                    variant: entry.variant,
+                    part: part.part.number(),
+                    answer: part.answer.clone(),
+                    parse_us: report.parse.as_micros(),
+                    solve_us: part.time.as_micros(),
+                    check: verdict.label(),
+                    expected: match verdict {
+                        Verdict::Pass => Some(part.answer.clone()),
+                        Verdict::Fail { expected } => Some(expected),
+                        Verdict::New => None,
+                    },
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).unwrap() + "\n"
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out =
+        "year,day,solution,variant,part,answer,parse_us,solve_us,check,expected\n".to_string();
+    for r in records {
+        let fields = [
+            r.year.to_string(),
+            r.day.to_string(),
+            csv_field(r.solution),
+            csv_field(r.variant),
+            r.part.to_string(),
+            csv_field(&r.answer),
+            r.parse_us.to_string(),
+            r.solve_us.to_string(),
+            r.check.to_string(),
+            csv_field(r.expected.as_deref().unwrap_or_default()),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Record> {
+        vec![Record {
+            year: 2022,
+            day: 5,
+            solution: "Day Five",
+            variant: "default",
+            part: 1,
+            answer: "CMZ".to_string(),
+            parse_us: 61,
+            solve_us: 11,
+            check: "FAIL",
+            expected: Some("VJS,\"FT\"".to_string()),
+        }]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let value: serde_json::Value = serde_json::from_str(&to_json(&sample())).unwrap();
+
+        assert_eq!(value[0]["year"], 2022);
+        assert_eq!(value[0]["day"], 5);
+        assert_eq!(value[0]["part"], 1);
+        assert_eq!(value[0]["answer"], "CMZ");
+        assert_eq!(value[0]["parse_us"], 61);
+        assert_eq!(value[0]["solve_us"], 11);
+        assert_eq!(value[0]["check"], "FAIL");
+    }
+
+    #[test]
+    fn test_to_csv_quotes_fields() {
+        assert_eq!(
+            to_csv(&sample()),
+            "year,day,solution,variant,part,answer,parse_us,solve_us,check,expected\n\
+             2022,5,Day Five,default,1,CMZ,61,11,FAIL,\"VJS,\"\"FT\"\"\"\n"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::Entry;
+
+/// Every known solution, alternates included, in year and day order.
+pub fn solutions() -> Vec<Entry> {
+    vec![
+        Entry::of::<day_1::DayOne>(),
+        Entry::of::<day_1::DayOneWide>(),
+        Entry::of::<day_2::DayTwo>(),
+        Entry::of::<day_2::DayTwoRayon>(),
+        Entry::of::<day_3::DayThree>(),
+        Entry::of::<day_4::DayFour>(),
+        Entry::of::<day_4::DayFourNom>(),
+        Entry::of::<day_5::DayFive>(),
+        Entry::of::<day_6::DaySix>(),
+    ]
+}
+
+/// The most recent year with a solution, used when no `--year` is given.
+pub fn latest_year() -> u16 {
+    solutions()
+        .iter()
+        .map(|entry| entry.year)
+        .max()
+        .unwrap_or(2022)
+}
@@ -0,0 +1,727 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::examples_dir;
+use clap::Args;
+
+use crate::registry;
+use crate::scrape::example_test;
+
+/// Crates for this year predate years in the layout and keep their plain
+/// `day_N` package names; later years are prefixed, e.g. `y2023_day_N`.
+const UNPREFIXED_YEAR: u16 = 2022;
+
+const NUMBERS: [&str; 25] = [
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+    "Twenty",
+    "Twenty-One",
+    "Twenty-Two",
+    "Twenty-Three",
+    "Twenty-Four",
+    "Twenty-Five",
+];
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day to create a crate for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Event year, defaults to the latest one with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Add a `<variant>` module with another implementation to the day's
+    /// existing crate
+    #[arg(long, value_parser = parse_variant)]
+    variant: Option<String>,
+    /// Puzzle title, for the crate docs
+    #[arg(long)]
+    title: Option<String>,
+}
+
+fn parse_variant(variant: &str) -> Result<String, String> {
+    let valid = variant.starts_with(|c: char| c.is_ascii_lowercase())
+        && variant
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if valid {
+        Ok(variant.to_string())
+    } else {
+        Err("use lowercase letters, digits and '_', e.g. 'rayon'".to_string())
+    }
+}
+
+/// Names a new solution goes by.
+struct Names {
+    year: u16,
+    day: u8,
+    /// `y2022/day_4`, the day's crate relative to the workspace root
+    dir: String,
+    /// `day_4`, or `y2023_day_4` outside 2022
+    krate: String,
+    /// `fast`, the module a variant lives in
+    variant: Option<String>,
+    /// `DayFourFast`
+    solution: String,
+    /// `Day Four (Fast)`
+    label: String,
+}
+
+impl Names {
+    fn new(year: u16, day: u8, variant: Option<&str>) -> Self {
+        let number = NUMBERS[day as usize - 1];
+        let mut krate = format!("day_{day}");
+        let mut solution = format!("Day{}", number.replace('-', ""));
+        let mut label = format!("Day {number}");
+        if let Some(variant) = variant {
+            let camel: String = variant
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                        .unwrap_or_default()
+                })
+                .collect();
+            solution.push_str(&camel);
+            label = format!("{label} ({camel})");
+        }
+        let dir = format!("y{year}/{krate}");
+        if year != UNPREFIXED_YEAR {
+            krate = format!("y{year}_{krate}");
+        }
+        Names {
+            year,
+            day,
+            dir,
+            krate,
+            variant: variant.map(str::to_string),
+            solution,
+            label,
+        }
+    }
+}
+
+/// Creates the crate, or adds a variant to it, and registers the solution
+/// with the runner and the benches.
+pub fn run(args: NewArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let names = Names::new(year, args.day, args.variant.as_deref());
+    let result = workspace_root().and_then(|root| match names.variant {
+        Some(_) => add_variant(&root, &names),
+        None => create(&root, &names, args.title.as_deref()),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}: {err}", names.krate);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Nearest directory holding a workspace `Cargo.toml`, walking up from the
+/// working directory.
+pub fn workspace_root() -> io::Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no workspace Cargo.toml above {}", cwd.display()),
+            )
+        })
+}
+
+fn create(root: &Path, names: &Names, title: Option<&str>) -> io::Result<()> {
+    let dir = root.join(&names.dir);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    // Check every file can be registered before writing anything.
+    let mut edits = vec![
+        (
+            root.join("Cargo.toml"),
+            add_member(&read(&root.join("Cargo.toml"))?, &names.dir)?,
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            insert_sorted(
+                &read(&root.join("aoc/Cargo.toml"))?,
+                "[dependencies]",
+                &path_dependency(names),
+                &names.krate,
+            )?,
+        ),
+        (
+            root.join("aoc_bench/Cargo.toml"),
+            insert_sorted(
+                &read(&root.join("aoc_bench/Cargo.toml"))?,
+                "[dev-dependencies]",
+                &path_dependency(names),
+                &names.krate,
+            )?,
+        ),
+    ];
+    edits.extend(solution_edits(root, names)?);
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), manifest(names))?;
+    fs::write(dir.join("src/main.rs"), main_rs(names))?;
+    fs::write(dir.join("src/lib.rs"), lib_rs(names, title))?;
+    println!("created {}", dir.display());
+
+    // The example test only makes sense once `aoc scrape` has an example.
+    if examples_dir()
+        .join(names.year.to_string())
+        .join(format!("day{}.txt", names.day))
+        .is_file()
+    {
+        fs::create_dir_all(dir.join("tests"))?;
+        fs::write(
+            dir.join("tests/example.rs"),
+            example_test(
+                &names.krate,
+                names.year,
+                names.day,
+                &[&names.solution],
+                Some("not solved yet"),
+            ),
+        )?;
+    }
+
+    write_edits(edits)
+}
+
+/// Adds `src/<variant>.rs` to the day's crate, exported from its `lib.rs`.
+fn add_variant(root: &Path, names: &Names) -> io::Result<()> {
+    let variant = names.variant.as_deref().unwrap_or_default();
+    let dir = root.join(&names.dir);
+    let lib_path = dir.join("src/lib.rs");
+    if !lib_path.is_file() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "{} does not exist, create the day first with `aoc new {}`",
+                dir.display(),
+                names.day
+            ),
+        ));
+    }
+    let module_path = dir.join("src").join(format!("{variant}.rs"));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    let mut edits = vec![(
+        lib_path.clone(),
+        export_module(&read(&lib_path)?, variant, &names.solution),
+    )];
+    let test_path = dir.join("tests/example.rs");
+    if test_path.is_file() {
+        let mut test = read(&test_path)?;
+        let name = format!(
+            "test_day{}_example_{}",
+            names.day,
+            names.solution.to_lowercase()
+        );
+        test.push_str(&format!(
+            "\n#[test]\n#[ignore = \"not solved yet\"]\nfn {name}() {{\n    aoc_common::assert_example::<{}::{}>();\n}}\n",
+            names.krate, names.solution
+        ));
+        edits.push((test_path, test));
+    }
+    edits.extend(solution_edits(root, names)?);
+
+    fs::write(&module_path, variant_rs(names))?;
+    println!("created {}", module_path.display());
+    write_edits(edits)
+}
+
+/// Registrations of the solution type with the runner and the benches.
+fn solution_edits(root: &Path, names: &Names) -> io::Result<Vec<(PathBuf, String)>> {
+    Ok(vec![
+        (
+            root.join("aoc/src/registry.rs"),
+            insert_sorted(
+                &read(&root.join("aoc/src/registry.rs"))?,
+                "vec![",
+                &format!(
+                    "        Entry::of::<{}::{}>(),",
+                    names.krate, names.solution
+                ),
+                &names.krate,
+            )?,
+        ),
+        (
+            root.join("aoc_bench/benches/days.rs"),
+            insert_sorted(
+                &read(&root.join("aoc_bench/benches/days.rs"))?,
+                "fn days(",
+                &format!(
+                    "    bench_solution::<{}::{}>(c);",
+                    names.krate, names.solution
+                ),
+                &names.krate,
+            )?,
+        ),
+    ])
+}
+
+fn write_edits(edits: Vec<(PathBuf, String)>) -> io::Result<()> {
+    for (path, text) in edits {
+        fs::write(&path, text)?;
+        println!("registered in {}", path.display());
+    }
+    Ok(())
+}
+
+/// Declares `module` in a crate root and re-exports `solution` from it, after
+/// the existing imports and modules.
+fn export_module(lib: &str, module: &str, solution: &str) -> String {
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    let declaration = [
+        format!("pub mod {module};"),
+        format!("pub use {module}::{solution};"),
+    ];
+    let items = lines
+        .iter()
+        .position(|line| line.starts_with("#[cfg(test)]"))
+        .unwrap_or(lines.len());
+    let last_import = lines[..items].iter().rposition(|line| {
+        ["use ", "pub use ", "mod ", "pub mod "]
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+    });
+    let at = match last_import {
+        Some(index) => {
+            lines.insert(index + 1, String::new());
+            index + 2
+        }
+        None => {
+            lines.insert(0, String::new());
+            0
+        }
+    };
+    for (offset, line) in declaration.into_iter().enumerate() {
+        lines.insert(at + offset, line);
+    }
+    lines.join("\n") + "\n"
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+fn unregistrable(what: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("can't find where to register the crate: {what}"),
+    )
+}
+
+/// Orders crates, or their `y2022/day_4` member paths, by year, day, then
+/// variant: `day_4` < `day_4_nom` < `day_10` < `y2023_day_1`.
+fn crate_key(name: &str) -> Option<(u16, u8, &str)> {
+    let (year, rest) = match name
+        .strip_prefix('y')
+        .and_then(|rest| rest.split_once(['/', '_']))
+    {
+        Some((year, rest)) => (year.parse().ok()?, rest),
+        None => (UNPREFIXED_YEAR, name),
+    };
+    let rest = rest.strip_prefix("day_")?;
+    let (day, variant) = rest.split_once('_').unwrap_or((rest, ""));
+    Some((year, day.parse().ok()?, variant))
+}
+
+/// First day crate name mentioned on a line.
+fn crate_on(line: &str) -> Option<&str> {
+    line.split(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+        .find(|word| crate_key(word).is_some())
+}
+
+/// Adds the member path `name` to the one-line `members = [...]` list,
+/// keeping day crates in year and day order after the others.
+fn add_member(manifest: &str, name: &str) -> io::Result<String> {
+    let mut out = vec![];
+    let mut found = false;
+    for line in manifest.lines() {
+        let Some(list) = line
+            .strip_prefix("members = [")
+            .and_then(|rest| rest.strip_suffix(']'))
+        else {
+            out.push(line.to_string());
+            continue;
+        };
+        found = true;
+        let mut members: Vec<String> = list
+            .split(',')
+            .map(|member| member.trim().trim_matches('"').to_string())
+            .filter(|member| !member.is_empty())
+            .collect();
+        if !members.iter().any(|member| member == name) {
+            members.push(name.to_string());
+        }
+        members.sort_by(|a, b| match (crate_key(a), crate_key(b)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Greater,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (None, None) => a.cmp(b),
+        });
+        let quoted: Vec<String> = members.iter().map(|m| format!("\"{m}\"")).collect();
+        out.push(format!("members = [{}]", quoted.join(", ")));
+    }
+    if !found {
+        return Err(unregistrable("no one-line `members = [...]`"));
+    }
+    Ok(out.join("\n") + "\n")
+}
+
+/// Inserts `new_line` among the day lines of the block opened by the line
+/// containing `opener`, keeping them in year and day order.
+fn insert_sorted(text: &str, opener: &str, new_line: &str, name: &str) -> io::Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.contains(opener))
+        .ok_or_else(|| unregistrable(&format!("no '{opener}'")))?
+        + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with('[') || line == "]" || line == "}"
+        })
+        .map_or(lines.len(), |at| start + at);
+
+    let key = crate_key(name).ok_or_else(|| unregistrable(name))?;
+    let mut at = end;
+    let mut last_day = None;
+    for (index, line) in lines.iter().enumerate().take(end).skip(start) {
+        let Some(other) = crate_on(line) else {
+            continue;
+        };
+        // Same dependency, or the same type of a crate with several variants.
+        if first_word(line) == first_word(new_line) {
+            return Ok(text.to_string());
+        }
+        if crate_key(other).is_some_and(|other| other > key) {
+            at = index;
+            break;
+        }
+        last_day = Some(index);
+    }
+    if at == end {
+        at = last_day.map_or(end, |index| index + 1);
+    }
+    lines.insert(at, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn first_word(line: &str) -> &str {
+    line.split_whitespace().next().unwrap_or_default()
+}
+
+fn path_dependency(names: &Names) -> String {
+    format!("{} = {{ path = \"../{}\" }}", names.krate, names.dir)
+}
+
+fn manifest(names: &Names) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version.workspace = true
+edition.workspace = true
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = {{ workspace = true }}
+"#,
+        names.krate
+    )
+}
+
+fn main_rs(names: &Names) -> String {
+    format!(
+        "use aoc_common::run;\nuse {}::{};\n\nfn main() {{\n    run::<{}>();\n}}\n",
+        names.krate, names.solution, names.solution
+    )
+}
+
+fn lib_rs(names: &Names, title: Option<&str>) -> String {
+    let Names {
+        year,
+        day,
+        solution,
+        label,
+        ..
+    } = names;
+    let heading = match title {
+        Some(title) => format!("Day {day}: {title}."),
+        None => format!("Day {day}."),
+    };
+    format!(
+        r#"//! {heading}
+
+use aoc_common::{{Error, Result, Solution}};
+
+/// Splits the input into lines.
+pub fn parse(input: &str) -> Result<Vec<&str>> {{
+    Ok(input.lines().collect())
+}}
+
+pub struct {solution};
+
+impl Solution for {solution} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const NAME: &'static str = "{label}";
+
+    type Parsed<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {{
+        parse(input)
+    }}
+
+    fn part_one(lines: &Self::Parsed<'_>) -> Result<Self::PartOne> {{
+        part_one(lines)
+    }}
+
+    fn part_two(lines: &Self::Parsed<'_>) -> Result<Self::PartTwo> {{
+        part_two(lines)
+    }}
+}}
+
+pub fn part_one(_lines: &[&str]) -> Result<u32> {{
+    Err(Error::no_answer("part one is not solved yet"))
+}}
+
+pub fn part_two(_lines: &[&str]) -> Result<u32> {{
+    Err(Error::no_answer("part two is not solved yet"))
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use aoc_common::local_input;
+
+    const SAMPLE_A: (&str, u32, u32) = ("", 0, 0);
+
+    #[test]
+    #[ignore = "fill in SAMPLE_A from the puzzle"]
+    fn test_day{day}_part1_sample() {{
+        let sample_res = part_one(&parse(SAMPLE_A.0).unwrap()).unwrap();
+        assert_eq!(sample_res, SAMPLE_A.1);
+    }}
+
+    #[test]
+    #[ignore = "fill in SAMPLE_A from the puzzle"]
+    fn test_day{day}_part2_sample() {{
+        let sample_res = part_two(&parse(SAMPLE_A.0).unwrap()).unwrap();
+        assert_eq!(sample_res, SAMPLE_A.2);
+    }}
+
+    #[test]
+    #[ignore = "needs inputs/{year}/day{day}.txt"]
+    fn test_day{day}_part1_actual() {{
+        let input = local_input({year}, {day}).unwrap();
+        let actual_res = part_one(&parse(&input).unwrap()).unwrap();
+        assert_eq!(actual_res, 0);
+    }}
+
+    #[test]
+    #[ignore = "needs inputs/{year}/day{day}.txt"]
+    fn test_day{day}_part2_actual() {{
+        let input = local_input({year}, {day}).unwrap();
+        let actual_res = part_two(&parse(&input).unwrap()).unwrap();
+        assert_eq!(actual_res, 0);
+    }}
+}}
+"#
+    )
+}
+
+fn variant_rs(names: &Names) -> String {
+    let Names {
+        year,
+        day,
+        solution,
+        label,
+        ..
+    } = names;
+    let variant = names.variant.as_deref().unwrap_or_default();
+    format!(
+        r#"//! Another take on day {day}, run as the `{variant}` variant.
+
+use aoc_common::{{Error, Result, Solution}};
+
+pub struct {solution};
+
+impl Solution for {solution} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const NAME: &'static str = "{label}";
+    const VARIANT: &'static str = "{variant}";
+
+    type Parsed<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {{
+        Ok(input.lines().collect())
+    }}
+
+    fn part_one(_lines: &Self::Parsed<'_>) -> Result<Self::PartOne> {{
+        Err(Error::no_answer("part one is not solved yet"))
+    }}
+
+    fn part_two(_lines: &Self::Parsed<'_>) -> Result<Self::PartTwo> {{
+        Err(Error::no_answer("part two is not solved yet"))
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        let names = Names::new(2022, 4, Some("nom"));
+        assert_eq!(names.dir, "y2022/day_4");
+        assert_eq!(names.krate, "day_4");
+        assert_eq!(names.solution, "DayFourNom");
+        assert_eq!(names.label, "Day Four (Nom)");
+
+        let names = Names::new(2023, 21, Some("bit_set"));
+        assert_eq!(names.dir, "y2023/day_21");
+        assert_eq!(names.krate, "y2023_day_21");
+        assert_eq!(names.solution, "DayTwentyOneBitSet");
+        assert_eq!(names.label, "Day Twenty-One (BitSet)");
+    }
+
+    #[test]
+    fn test_crate_order() {
+        assert!(crate_key("day_4") < crate_key("day_4_nom"));
+        assert!(crate_key("day_4_nom") < crate_key("day_10"));
+        assert!(crate_key("day_10") < crate_key("y2023_day_1"));
+        assert_eq!(crate_key("y2022/day_4_nom"), crate_key("day_4_nom"));
+        assert_eq!(crate_key("aoc_common"), None);
+        assert_eq!(crate_key("y2022"), None);
+        assert_eq!(
+            crate_on("    Entry::of::<day_4_nom::DayFourNom>(),"),
+            Some("day_4_nom")
+        );
+        assert_eq!(
+            crate_on("day_10 = { path = \"../y2022/day_10\" }"),
+            Some("day_10")
+        );
+        assert_eq!(
+            crate_on("    bench_solution::<y2023_day_1::DayOne>(c);"),
+            Some("y2023_day_1")
+        );
+        assert_eq!(crate_on("serde = { workspace = true }"), None);
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\"aoc\", \"y2022/day_1\", \"y2022/day_4\", \"y2022/day_4_nom\", \"y2022/day_10\"]\n";
+
+        assert_eq!(
+            add_member(manifest, "y2022/day_4_fast").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"y2022/day_1\", \"y2022/day_4\", \"y2022/day_4_fast\", \"y2022/day_4_nom\", \"y2022/day_10\"]\n"
+        );
+        assert!(add_member(manifest, "y2023/day_1")
+            .unwrap()
+            .contains("\"y2022/day_10\", \"y2023/day_1\"]"));
+        assert!(add_member("[workspace]\n", "day_2").is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let manifest = "[dependencies]\naoc_common = { workspace = true }\nday_1 = { path = \"../y2022/day_1\" }\nday_6 = { path = \"../y2022/day_6\" }\nserde = { workspace = true }\n\n[dev-dependencies]\nday_9 = 1\n";
+
+        let added = insert_sorted(manifest, "[dependencies]", "day_7 = x", "day_7").unwrap();
+        assert!(added.contains("day_6 = { path = \"../y2022/day_6\" }\nday_7 = x\nserde"));
+        let added = insert_sorted(manifest, "[dependencies]", "day_2 = x", "day_2").unwrap();
+        assert!(added.contains("day_1 = { path = \"../y2022/day_1\" }\nday_2 = x\nday_6"));
+        assert_eq!(
+            insert_sorted(manifest, "[dependencies]", "day_6 = x", "day_6").unwrap(),
+            manifest
+        );
+
+        let registry = "    vec![\n        Entry::of::<day_1::DayOne>(),\n    ]\n}\n";
+        let added = insert_sorted(
+            registry,
+            "vec![",
+            "        Entry::of::<day_2::DayTwo>(),",
+            "day_2",
+        )
+        .unwrap();
+        assert!(added.ends_with("DayOne>(),\n        Entry::of::<day_2::DayTwo>(),\n    ]\n}\n"));
+
+        let variant = "        Entry::of::<day_1::DayOneFast>(),";
+        let added = insert_sorted(registry, "vec![", variant, "day_1").unwrap();
+        assert!(added.contains("DayOne>(),\n        Entry::of::<day_1::DayOneFast>(),\n    ]"));
+        assert_eq!(
+            insert_sorted(&added, "vec![", variant, "day_1").unwrap(),
+            added
+        );
+
+        assert!(insert_sorted(registry, "fn days(", "x", "day_2").is_err());
+    }
+
+    #[test]
+    fn test_export_module() {
+        let lib = "//! Day 4\n\nuse aoc_common::Solution;\n\npub struct DayFour;\n\n#[cfg(test)]\nmod tests {\n    use super::*;\n}\n";
+        assert_eq!(
+            export_module(lib, "nom", "DayFourNom"),
+            "//! Day 4\n\nuse aoc_common::Solution;\n\npub mod nom;\npub use nom::DayFourNom;\n\npub struct DayFour;\n\n#[cfg(test)]\nmod tests {\n    use super::*;\n}\n"
+        );
+        assert_eq!(
+            export_module("pub struct DayFour;\n", "nom", "DayFourNom"),
+            "pub mod nom;\npub use nom::DayFourNom;\n\npub struct DayFour;\n"
+        );
+    }
+}
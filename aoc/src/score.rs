@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_common::{DayError, InputSource};
+use clap::Args;
+use day_2::Rules;
+
+#[derive(Args)]
+pub struct ScoreArgs {
+    /// Rules file, like the presets in y2022/day_2/rules
+    rules: PathBuf,
+    /// Read the guide from this file, or `-` for stdin, instead of inputs/2022/day2.txt
+    #[arg(long)]
+    input: Option<String>,
+    /// Download the input if it is missing locally
+    #[arg(long)]
+    fetch: bool,
+    /// Session cookie used with --fetch, overriding AOC_SESSION and the config file
+    #[arg(long)]
+    session: Option<String>,
+}
+
+/// Scores a 2022 day 2 strategy guide under the rules in a TOML file, so that
+/// other versions of the game need no new variant.
+pub fn run(args: ScoreArgs) -> ExitCode {
+    let rules = match Rules::load(&args.rules) {
+        Ok(rules) => rules,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let source = InputSource::from_arg(args.input.as_deref(), args.fetch, args.session.as_deref());
+    let guide = match source.read(2022, 2) {
+        Ok(guide) => guide,
+        Err(err) => {
+            eprintln!("day 2: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match rules.score_par(&guide) {
+        Ok(score) => {
+            println!("{score}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", DayError { day: 2, error });
+            ExitCode::FAILURE
+        }
+    }
+}
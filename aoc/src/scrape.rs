@@ -0,0 +1,207 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::{examples_dir, scrape, Answers, Example, Part};
+use clap::Args;
+
+const HEADER: &str = "# Answers to the puzzle examples, written by `aoc scrape`.\n";
+
+#[derive(Args)]
+pub struct ScrapeArgs {
+    /// Puzzle pages saved from adventofcode.com
+    #[arg(required = true)]
+    pages: Vec<PathBuf>,
+    /// Year of pages saved without their `<title>`
+    #[arg(long)]
+    year: Option<u16>,
+}
+
+/// Writes each page's example into the examples directory, and a test using
+/// it into every crate for that day that lacks one.
+pub fn run(args: ScrapeArgs) -> ExitCode {
+    let dir = examples_dir();
+    let mut failed = false;
+    for page in &args.pages {
+        let scraped = fs::read_to_string(page)
+            .map_err(|err| err.to_string())
+            .and_then(|html| scrape(&html));
+        let result = match scraped {
+            Ok(example) => match example.year.or(args.year) {
+                Some(year) => save(&dir, year, &example),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "no year in the page's <title>, pass --year",
+                )),
+            },
+            Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+        if let Err(err) = result {
+            eprintln!("{}: {err}", page.display());
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn save(dir: &Path, year: u16, example: &Example) -> io::Result<()> {
+    let day = example.day;
+    let year_dir = dir.join(year.to_string());
+    fs::create_dir_all(&year_dir)?;
+    let input_path = year_dir.join(format!("day{day}.txt"));
+    fs::write(&input_path, format!("{}\n", example.input))?;
+
+    let answers_path = dir.join("answers.toml");
+    let mut answers = Answers::load(&answers_path)?;
+    for (part, answer) in Part::BOTH.into_iter().zip(&example.answers) {
+        answers.set(year, day, part, answer);
+    }
+    fs::write(&answers_path, answers.to_toml_with_header(HEADER))?;
+    println!(
+        "{year} day {day}: {}, wrote {} with answers {}",
+        example.title,
+        input_path.display(),
+        example.answers.join(", ")
+    );
+
+    let root = match dir.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    for crate_dir in day_crates(&root.join(format!("y{year}")), day)? {
+        let test_path = crate_dir.join("tests").join("example.rs");
+        if test_path.exists() {
+            continue;
+        }
+        let lib = fs::read_to_string(crate_dir.join("src").join("lib.rs"))?;
+        let solutions = solution_types(&lib);
+        if solutions.is_empty() {
+            continue;
+        }
+        let manifest = fs::read_to_string(crate_dir.join("Cargo.toml"))?;
+        let Some(name) = package_name(&manifest) else {
+            continue;
+        };
+        fs::create_dir_all(crate_dir.join("tests"))?;
+        fs::write(&test_path, example_test(&name, year, day, &solutions, None))?;
+        println!("wrote {}", test_path.display());
+    }
+    Ok(())
+}
+
+/// Crates in directories named `day_N` or `day_N_<variant>` under
+/// `year_dir`.
+pub fn day_crates(year_dir: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let name = format!("day_{day}");
+    let read = match fs::read_dir(year_dir) {
+        Ok(read) => read,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+    let mut crates: Vec<PathBuf> = read
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|file| file.to_str())
+                .is_some_and(|file| file == name || file.starts_with(&format!("{name}_")))
+                && path.join("src").join("lib.rs").is_file()
+        })
+        .collect();
+    crates.sort();
+    Ok(crates)
+}
+
+/// Name a crate is used by in code, from its `Cargo.toml`.
+pub fn package_name(manifest: &str) -> Option<String> {
+    manifest
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("name = "))
+        .map(|name| name.trim_matches('"').replace('-', "_"))
+}
+
+/// Types a crate implements `Solution` for.
+fn solution_types(lib: &str) -> Vec<&str> {
+    lib.lines()
+        .filter_map(|line| line.trim().strip_prefix("impl Solution for "))
+        .filter_map(|rest| {
+            rest.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Test module checking each solution against the example, optionally
+/// ignored with the given reason.
+pub fn example_test(
+    crate_name: &str,
+    year: u16,
+    day: u8,
+    solutions: &[&str],
+    ignore: Option<&str>,
+) -> String {
+    let attributes = match ignore {
+        Some(reason) => format!("#[test]\n#[ignore = \"{reason}\"]"),
+        None => "#[test]".to_string(),
+    };
+    let mut out = format!(
+        "//! Generated by `aoc scrape`, checks the puzzle's example from `examples/{year}/day{day}.txt`.\n"
+    );
+    for solution in solutions {
+        let test = if solutions.len() == 1 {
+            format!("test_day{day}_example")
+        } else {
+            format!("test_day{day}_example_{}", solution.to_lowercase())
+        };
+        out.push_str(&format!(
+            "\n{attributes}\nfn {test}() {{\n    aoc_common::assert_example::<{crate_name}::{solution}>();\n}}\n"
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution_types() {
+        let lib = "pub struct DayFour;\n\nimpl Solution for DayFour {\n}\nimpl Solution for Other<'_> {}\n";
+        assert_eq!(solution_types(lib), ["DayFour", "Other"]);
+        assert!(solution_types("fn main() {}").is_empty());
+    }
+
+    #[test]
+    fn test_package_name() {
+        let manifest = "[package]\nname = \"y2023_day_1\"\nversion.workspace = true\n";
+        assert_eq!(package_name(manifest).as_deref(), Some("y2023_day_1"));
+        assert_eq!(package_name("[workspace]\n"), None);
+    }
+
+    #[test]
+    fn test_example_test() {
+        assert_eq!(
+            example_test("day_4_nom", 2022, 4, &["DayFourNom"], None),
+            "//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day4.txt`.\n\
+             \n\
+             #[test]\n\
+             fn test_day4_example() {\n    \
+             aoc_common::assert_example::<day_4_nom::DayFourNom>();\n\
+             }\n"
+        );
+        assert!(example_test(
+            "day_2",
+            2022,
+            2,
+            &["DayTwo", "DayTwoRayon"],
+            Some("not solved yet")
+        )
+        .contains("#[test]\n#[ignore = \"not solved yet\"]\nfn test_day2_example_daytworayon()"));
+    }
+}
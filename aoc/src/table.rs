@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use aoc_common::{Answers, Entry, Report};
+
+const HEADERS: [&str; 7] = [
+    "Day",
+    "Solution",
+    "Part",
+    "Answer",
+    "Parse (µs)",
+    "Solve (µs)",
+    "Check",
+];
+
+/// Renders one row per solved part as an aligned text table, checking each
+/// answer against the manifest.
+pub fn render(results: &[(Entry, Report)], answers: &Answers) -> String {
+    let mut rows: Vec<[String; 7]> = vec![];
+    for (entry, report) in results {
+        for part in &report.parts {
+            rows.push([
+                entry.day.to_string(),
+                entry.name.to_string(),
+                part.part.number().to_string(),
+                part.answer.clone(),
+                report.parse.as_micros().to_string(),
+                part.time.as_micros().to_string(),
+                answers
+                    .check(entry.year, entry.day, part.part, &part.answer)
+                    .to_string(),
+            ]);
+        }
+    }
+
+    let mut widths = HEADERS.map(|h| h.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let total: u128 = results.iter().map(|(_, r)| r.total().as_micros()).sum();
+
+    let mut out = String::new();
+    write_row(&mut out, &HEADERS.map(String::from), &widths);
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    writeln!(out, "{}", rule.join("-+-")).unwrap();
+    for row in &rows {
+        write_row(&mut out, row, &widths);
+    }
+    writeln!(out, "Total Time: {total} microseconds").unwrap();
+    out
+}
+
+fn write_row(out: &mut String, row: &[String; 7], widths: &[usize; 7]) {
+    let cells: Vec<String> = row
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect();
+    writeln!(out, "{}", cells.join(" | ").trim_end()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Part, PartReport};
+    use std::time::Duration;
+
+    #[test]
+    fn test_render_aligns_columns() {
+        let entry = Entry {
+            year: 2022,
+            day: 6,
+            name: "Day Six",
+            variant: "default",
+            solve: |_, _| unreachable!(),
+        };
+        let report = Report {
+            parse: Duration::from_micros(3),
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    answer: "1361".to_string(),
+                    time: Duration::from_micros(10),
+                },
+                PartReport {
+                    part: Part::Two,
+                    answer: "3263".to_string(),
+                    time: Duration::from_micros(120),
+                },
+            ],
+        };
+        let answers = Answers::parse("[2022.day6]\npart1 = \"1361\"\n").unwrap();
+
+        let table = render(&[(entry, report)], &answers);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "Day | Solution | Part | Answer | Parse (µs) | Solve (µs) | Check"
+        );
+        assert_eq!(
+            lines[2],
+            "6   | Day Six  | 1    | 1361   | 3          | 10         | PASS"
+        );
+        assert_eq!(
+            lines[3],
+            "6   | Day Six  | 2    | 3263   | 3          | 120        | NEW"
+        );
+        assert_eq!(lines[4], "Total Time: 133 microseconds");
+    }
+}
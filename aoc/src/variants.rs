@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use aoc_common::{Entry, Part, Report};
+
+/// Results grouped by puzzle, keeping only puzzles solved by several variants.
+fn by_puzzle(results: &[(Entry, Report)]) -> BTreeMap<(u16, u8), Vec<&(Entry, Report)>> {
+    let mut puzzles: BTreeMap<(u16, u8), Vec<_>> = BTreeMap::new();
+    for result in results {
+        puzzles
+            .entry((result.0.year, result.0.day))
+            .or_default()
+            .push(result);
+    }
+    puzzles.retain(|_, variants| variants.len() > 1);
+    puzzles
+}
+
+/// One message per part where a day's variants gave different answers.
+pub fn disagreements(results: &[(Entry, Report)]) -> Vec<String> {
+    let mut messages = vec![];
+    for ((year, day), variants) in by_puzzle(results) {
+        for part in Part::BOTH {
+            let answers: Vec<(&str, &str)> = variants
+                .iter()
+                .filter_map(|(entry, report)| {
+                    let solved = report.parts.iter().find(|p| p.part == part)?;
+                    Some((entry.variant, solved.answer.as_str()))
+                })
+                .collect();
+            if answers.windows(2).any(|pair| pair[0].1 != pair[1].1) {
+                let listed: Vec<String> = answers
+                    .iter()
+                    .map(|(variant, answer)| format!("{variant} = {answer}"))
+                    .collect();
+                messages.push(format!(
+                    "{year} day {day} part {part}: variants disagree: {}",
+                    listed.join(", ")
+                ));
+            }
+        }
+    }
+    messages
+}
+
+/// A line per day with several variants, fastest first, each with how many
+/// times slower than the fastest it was.
+pub fn compare(results: &[(Entry, Report)]) -> String {
+    let mut out = String::new();
+    for ((year, day), mut variants) in by_puzzle(results) {
+        variants.sort_by_key(|(_, report)| report.total());
+        let fastest = variants[0].1.total().as_secs_f64().max(f64::EPSILON);
+        let timings: Vec<String> = variants
+            .iter()
+            .enumerate()
+            .map(|(index, (entry, report))| {
+                let micros = report.total().as_micros();
+                if index == 0 {
+                    format!("{} {micros}µs", entry.variant)
+                } else {
+                    let ratio = report.total().as_secs_f64() / fastest;
+                    format!("{} {micros}µs ({ratio:.2}x)", entry.variant)
+                }
+            })
+            .collect();
+        writeln!(out, "{year} day {day} variants: {}", timings.join(", ")).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::PartReport;
+    use std::time::Duration;
+
+    fn result(day: u8, variant: &'static str, answers: [&str; 2], micros: u64) -> (Entry, Report) {
+        let entry = Entry {
+            year: 2022,
+            day,
+            name: "Day",
+            variant,
+            solve: |_, _| unreachable!(),
+        };
+        let parts = Part::BOTH
+            .into_iter()
+            .zip(answers)
+            .map(|(part, answer)| PartReport {
+                part,
+                answer: answer.to_string(),
+                time: Duration::from_micros(micros / 2),
+            })
+            .collect();
+        let report = Report {
+            parse: Duration::ZERO,
+            parts,
+        };
+        (entry, report)
+    }
+
+    #[test]
+    fn test_disagreements() {
+        let results = [
+            result(3, "default", ["157", "70"], 10),
+            result(4, "handwritten", ["2", "4"], 10),
+            result(4, "nom", ["2", "5"], 10),
+        ];
+
+        assert_eq!(
+            disagreements(&results),
+            ["2022 day 4 part Two: variants disagree: handwritten = 4, nom = 5"]
+        );
+        assert!(disagreements(&results[..2]).is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let results = [
+            result(2, "sequential", ["15", "12"], 300),
+            result(2, "rayon", ["15", "12"], 100),
+            result(3, "default", ["157", "70"], 10),
+        ];
+
+        assert_eq!(
+            compare(&results),
+            "2022 day 2 variants: rayon 100µs, sequential 300µs (3.00x)\n"
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_common::{examples_dir, local_input_path};
+use clap::Args;
+use serde::Deserialize;
+
+use crate::registry;
+use crate::scaffold::workspace_root;
+use crate::scrape::{day_crates, package_name};
+
+/// How often watched files are checked for changes.
+const POLL: Duration = Duration::from_millis(300);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Event year, defaults to the latest one with solutions
+    #[arg(long)]
+    year: Option<u16>,
+    /// Download the input if it is missing locally
+    #[arg(long)]
+    fetch: bool,
+}
+
+/// One solved part as printed by `aoc run --format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Solved {
+    pub solution: String,
+    pub part: u8,
+    pub answer: String,
+    pub parse_us: u128,
+    pub solve_us: u128,
+    pub check: String,
+}
+
+/// Rebuilds and re-runs the day each time one of its files is saved, until
+/// interrupted.
+pub fn run(args: WatchArgs) -> ExitCode {
+    let year = args.year.unwrap_or_else(registry::latest_year);
+    let watched = workspace_root().and_then(|root| {
+        let crates = day_crates(&root.join(format!("y{year}")), args.day)?;
+        let mut packages = vec![];
+        for dir in &crates {
+            if let Some(name) = package_name(&fs::read_to_string(dir.join("Cargo.toml"))?) {
+                packages.push(name);
+            }
+        }
+        let mut paths = crates;
+        paths.push(root.join("aoc_common").join("src"));
+        paths.push(
+            examples_dir()
+                .join(year.to_string())
+                .join(format!("day{}.txt", args.day)),
+        );
+        paths.push(local_input_path(year, args.day));
+        Ok((root, packages, paths))
+    });
+    let (root, packages, paths) = match watched {
+        Ok(watched) if !watched.1.is_empty() => watched,
+        Ok(_) => {
+            eprintln!("no crates for {year} day {} to watch", args.day);
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "watching {} for {year} day {}, Ctrl-C to stop",
+        packages.join(", "),
+        args.day
+    );
+    let mut seen = snapshot(&paths);
+    let mut previous: Option<Vec<Solved>> = None;
+    loop {
+        test_samples(&root, &packages);
+        let current = run_day(&root, year, args.day, args.fetch);
+        if let Some(current) = &current {
+            print!("{}", diff(previous.as_deref(), current));
+            previous = Some(current.clone());
+        }
+
+        let changed = wait_for_change(&paths, &mut seen);
+        println!("\n--- changed: {}", changed.display());
+    }
+}
+
+/// Modification times of every file under `paths`.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    fn visit(path: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+        let Ok(meta) = fs::metadata(path) else {
+            return;
+        };
+        if meta.is_dir() {
+            // Build output is rewritten on every cycle.
+            if path.file_name().is_some_and(|name| name == "target") {
+                return;
+            }
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), times);
+            }
+        } else if let Ok(modified) = meta.modified() {
+            times.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut times = BTreeMap::new();
+    for path in paths {
+        visit(path, &mut times);
+    }
+    times
+}
+
+/// First path added, removed or modified between two snapshots.
+fn changed(
+    before: &BTreeMap<PathBuf, SystemTime>,
+    after: &BTreeMap<PathBuf, SystemTime>,
+) -> Option<PathBuf> {
+    after
+        .iter()
+        .find(|(path, time)| before.get(*path) != Some(time))
+        .map(|(path, _)| path.clone())
+        .or_else(|| {
+            before
+                .keys()
+                .find(|path| !after.contains_key(*path))
+                .cloned()
+        })
+}
+
+/// Blocks until a watched file changes and editors have finished saving.
+fn wait_for_change(paths: &[PathBuf], seen: &mut BTreeMap<PathBuf, SystemTime>) -> PathBuf {
+    loop {
+        thread::sleep(POLL);
+        let now = snapshot(paths);
+        if let Some(path) = changed(seen, &now) {
+            *seen = now;
+            // Let a burst of saves settle before rebuilding.
+            loop {
+                thread::sleep(POLL);
+                let settled = snapshot(paths);
+                if changed(seen, &settled).is_none() {
+                    break;
+                }
+                *seen = settled;
+            }
+            return path;
+        }
+    }
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+/// Runs each crate's tests, which hold the puzzle's samples, and prints a
+/// summary, or the output when any fail.
+fn test_samples(root: &Path, packages: &[String]) {
+    for package in packages {
+        let output = cargo(root)
+            .args(["test", "--quiet", "-p", package])
+            .output();
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => {
+                println!("{package}: cargo test: {err}");
+                continue;
+            }
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (passed, failed) = test_counts(&stdout);
+        if output.status.success() {
+            println!("{package}: samples ok, {passed} passed");
+        } else {
+            println!("{package}: samples FAILED, {passed} passed, {failed} failed");
+            print!("{stdout}{}", String::from_utf8_lossy(&output.stderr));
+        }
+    }
+}
+
+/// Passed and failed totals from the `test result:` lines of `cargo test`.
+fn test_counts(output: &str) -> (u32, u32) {
+    let count = |line: &str, label: &str| -> u32 {
+        line.split(';')
+            .find_map(|field| {
+                field
+                    .trim()
+                    .strip_suffix(label)?
+                    .rsplit(' ')
+                    .next()?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or_default()
+    };
+    output
+        .lines()
+        .filter(|line| line.starts_with("test result:"))
+        .fold((0, 0), |(passed, failed), line| {
+            (
+                passed + count(line, " passed"),
+                failed + count(line, " failed"),
+            )
+        })
+}
+
+/// Solves the real input with a freshly built runner.
+fn run_day(root: &Path, year: u16, day: u8, fetch: bool) -> Option<Vec<Solved>> {
+    let (year, day) = (year.to_string(), day.to_string());
+    let mut command = cargo(root);
+    command.args([
+        "run",
+        "--quiet",
+        "--release",
+        "-p",
+        "aoc",
+        "--",
+        "run",
+        &day,
+        "--year",
+        &year,
+        "--format",
+        "json",
+    ]);
+    if fetch {
+        command.arg("--fetch");
+    }
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(err) => {
+            println!("cargo run: {err}");
+            return None;
+        }
+    };
+    print!("{}", String::from_utf8_lossy(&output.stderr));
+    match serde_json::from_slice(&output.stdout) {
+        Ok(solved) => Some(solved),
+        Err(_) => {
+            println!("no answers from the real input");
+            None
+        }
+    }
+}
+
+/// One line per solved part, noting what changed since the previous run.
+fn diff(previous: Option<&[Solved]>, current: &[Solved]) -> String {
+    let mut out = String::new();
+    for now in current {
+        let before = previous.and_then(|previous| {
+            previous
+                .iter()
+                .find(|p| p.solution == now.solution && p.part == now.part)
+        });
+        let answer = match before {
+            Some(before) if before.answer != now.answer => {
+                format!("{} (was {})", now.answer, before.answer)
+            }
+            Some(_) => format!("{} (unchanged)", now.answer),
+            None => now.answer.clone(),
+        };
+        let total = now.parse_us + now.solve_us;
+        let timing = match before {
+            Some(before) => {
+                let was = before.parse_us + before.solve_us;
+                let change = (total as f64 - was as f64) / (was.max(1) as f64) * 100.0;
+                format!("{total}µs (was {was}µs, {change:+.0}%)")
+            }
+            None => format!("{total}µs"),
+        };
+        writeln!(
+            out,
+            "{} part {}: {answer} [{}] {timing}",
+            now.solution, now.part, now.check
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(answer: &str, parse_us: u128, solve_us: u128) -> Solved {
+        Solved {
+            solution: "Day Five".to_string(),
+            part: 1,
+            answer: answer.to_string(),
+            parse_us,
+            solve_us,
+            check: "PASS".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = [solved("CMZ", 50, 50)];
+        assert_eq!(diff(None, &first), "Day Five part 1: CMZ [PASS] 100µs\n");
+        assert_eq!(
+            diff(Some(&first), &[solved("CMZ", 40, 35)]),
+            "Day Five part 1: CMZ (unchanged) [PASS] 75µs (was 100µs, -25%)\n"
+        );
+        assert_eq!(
+            diff(Some(&first), &[solved("MCD", 100, 100)]),
+            "Day Five part 1: MCD (was CMZ) [PASS] 200µs (was 100µs, +100%)\n"
+        );
+    }
+
+    #[test]
+    fn test_test_counts() {
+        let output = "\nrunning 4 tests\n....\ntest result: ok. 4 passed; 0 failed; 2 ignored; 0 measured; 0 filtered out; finished in 0.00s\n\ntest result: FAILED. 0 passed; 1 failed; 0 ignored\n";
+        assert_eq!(test_counts(output), (4, 1));
+        assert_eq!(test_counts("error: could not compile"), (0, 0));
+    }
+
+    #[test]
+    fn test_changed() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        let paths = [dir.clone(), dir.join("missing.txt")];
+
+        let before = snapshot(&paths);
+        assert_eq!(changed(&before, &snapshot(&paths)), None);
+        fs::write(dir.join("target/out"), "ignored").unwrap();
+        assert_eq!(changed(&before, &snapshot(&paths)), None);
+        fs::write(dir.join("missing.txt"), "").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(changed(&before, &after), Some(dir.join("missing.txt")));
+        fs::remove_file(dir.join("src/lib.rs")).unwrap();
+        assert_eq!(
+            changed(&after, &snapshot(&paths)),
+            Some(dir.join("src/lib.rs"))
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+//! Runs `aoc new` inside a scratch copy of the files it registers crates in.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+fn copy(from: &Path, to: &Path, file: &str) {
+    fs::create_dir_all(to.join(file).parent().unwrap()).unwrap();
+    fs::copy(from.join(file), to.join(file)).unwrap();
+}
+
+#[test]
+fn test_new_day_and_variant() {
+    let repo = Path::new(ROOT).parent().unwrap();
+    let root = env::temp_dir().join(format!("aoc_new_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for file in [
+        "Cargo.toml",
+        "aoc/Cargo.toml",
+        "aoc/src/registry.rs",
+        "aoc_bench/Cargo.toml",
+        "aoc_bench/benches/days.rs",
+    ] {
+        copy(repo, &root, file);
+    }
+    let new = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .current_dir(root.join("aoc"))
+            .env("AOC_EXAMPLES_DIR", root.join("examples"))
+            .arg("new")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = new(&["7", "--title", "No Space Left On Device"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let manifest_before = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    let output = new(&["7", "--variant", "fast"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+        manifest_before
+    );
+    let output = new(&["1", "--year", "2023"]);
+    assert!(output.status.success());
+
+    let lib = fs::read_to_string(root.join("y2022/day_7/src/lib.rs")).unwrap();
+    assert!(lib.starts_with("//! Day 7: No Space Left On Device.\n"));
+    assert!(lib.contains("pub struct DaySeven;"));
+    assert!(lib.contains("const YEAR: u16 = 2022;"));
+    assert!(lib.contains("const NAME: &'static str = \"Day Seven\";"));
+    assert!(lib.contains("#[ignore = \"needs inputs/2022/day7.txt\"]"));
+    assert!(lib.contains("pub mod fast;\npub use fast::DaySevenFast;\n"));
+    let fast = fs::read_to_string(root.join("y2022/day_7/src/fast.rs")).unwrap();
+    assert!(fast.contains("pub struct DaySevenFast;"));
+    assert!(fast.contains("const VARIANT: &'static str = \"fast\";"));
+    assert!(!root.join("y2022/day_7/tests").exists());
+    let main = fs::read_to_string(root.join("y2023/day_1/src/main.rs")).unwrap();
+    assert!(main.contains("use y2023_day_1::DayOne;"));
+    let lib = fs::read_to_string(root.join("y2023/day_1/src/lib.rs")).unwrap();
+    assert!(lib.contains("const YEAR: u16 = 2023;"));
+
+    let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("\"y2022/day_6\", \"y2022/day_7\", \"y2023/day_1\"]"));
+    let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+    assert!(registry.contains(
+        "Entry::of::<day_7::DaySeven>(),\n        Entry::of::<day_7::DaySevenFast>(),\n        Entry::of::<y2023_day_1::DayOne>(),\n    ]"
+    ));
+    let deps = fs::read_to_string(root.join("aoc_bench/Cargo.toml")).unwrap();
+    assert!(deps.contains("day_7 = { path = \"../y2022/day_7\" }"));
+    assert!(deps.contains("y2023_day_1 = { path = \"../y2023/day_1\" }"));
+    let benches = fs::read_to_string(root.join("aoc_bench/benches/days.rs")).unwrap();
+    assert!(benches.contains("bench_solution::<y2023_day_1::DayOne>(c);\n}"));
+
+    let again = new(&["7"]);
+    assert!(!again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already exists"));
+    let again = new(&["7", "--variant", "fast"]);
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already exists"));
+    let missing = new(&["8", "--variant", "fast"]);
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("create the day first"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,61 @@
+//! Runs `aoc score` on a guide given through stdin and through a file.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+fn score(args: &[&str], stdin: &str) -> Output {
+    let rules = Path::new(ROOT).join("../y2022/day_2/rules/rpsls.toml");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("score")
+        .arg(rules)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_score_with_rules_file() {
+    let output = score(&["--input", "-"], "R K\nK L\nK K\n");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "10\n");
+
+    let guide = env::temp_dir().join(format!("aoc_score_{}.txt", std::process::id()));
+    fs::write(&guide, "R K\nR X\n").unwrap();
+    let output = score(&["--input", guide.to_str().unwrap()], "");
+    fs::remove_file(&guide).unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "day 2, line 2, column 3: invalid hand 'X'\n"
+    );
+}
+
+#[test]
+fn test_score_reports_bad_rules() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["score", "no/such/rules.toml", "--input", "-"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("no/such/rules.toml: "));
+}
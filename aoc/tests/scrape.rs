@@ -0,0 +1,122 @@
+//! Runs `aoc scrape` on a saved page inside a scratch workspace.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head>
+<title>Day 1 - Advent of Code 2022</title>
+</head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>For example:</p>
+<pre><code>1000
+2000
+
+3000
+</code></pre>
+<ul><li>The first Elf is carrying <code><em>3000</em></code> Calories.</li></ul>
+<p>In the example above, this is <em><code>3000</code></em>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+#[test]
+fn test_scrape_writes_example_and_test() {
+    let root = env::temp_dir().join(format!("aoc_scrape_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for name in ["day_1", "day_1_fast", "day_10"] {
+        let dir = root.join("y2022").join(name);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\n"),
+        )
+        .unwrap();
+    }
+    fs::write(
+        root.join("y2022/day_1/src/lib.rs"),
+        "pub struct DayOne;\n\nimpl Solution for DayOne {}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("y2022/day_1_fast/src/lib.rs"),
+        "pub struct DayOneFast;\n\nimpl Solution for DayOneFast {}\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("y2022/day_10/src/lib.rs"),
+        "impl Solution for DayTen {}\n",
+    )
+    .unwrap();
+    fs::write(root.join("day1.html"), PAGE).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env("AOC_EXAMPLES_DIR", root.join("examples"))
+        .arg("scrape")
+        .arg(root.join("day1.html"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        fs::read_to_string(root.join("examples/2022/day1.txt")).unwrap(),
+        "1000\n2000\n\n3000\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("examples/answers.toml")).unwrap(),
+        "# Answers to the puzzle examples, written by `aoc scrape`.\n\n[2022.day1]\npart1 = \"3000\"\n"
+    );
+    let test = fs::read_to_string(root.join("y2022/day_1/tests/example.rs")).unwrap();
+    assert!(test.contains("aoc_common::assert_example::<day_1::DayOne>();"));
+    let test = fs::read_to_string(root.join("y2022/day_1_fast/tests/example.rs")).unwrap();
+    assert!(test.contains("aoc_common::assert_example::<day_1_fast::DayOneFast>();"));
+    assert!(!root.join("y2022/day_10/tests").exists());
+
+    let untitled = PAGE.replace("<title>Day 1 - Advent of Code 2022</title>", "");
+    fs::write(root.join("untitled.html"), untitled).unwrap();
+    let scrape = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .env("AOC_EXAMPLES_DIR", root.join("examples"))
+            .arg("scrape")
+            .arg(root.join("untitled.html"))
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let output = scrape(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("pass --year"));
+    assert!(scrape(&["--year", "2021"]).status.success());
+    assert!(root.join("examples/2021/day1.txt").is_file());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_scrape_rejects_other_pages() {
+    let page = env::temp_dir().join(format!("aoc_scrape_bad_{}.html", std::process::id()));
+    fs::write(&page, "<html><p>Not a puzzle</p></html>").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .env(
+            "AOC_EXAMPLES_DIR",
+            env::temp_dir().join("aoc_scrape_unused"),
+        )
+        .arg("scrape")
+        .arg(&page)
+        .output()
+        .unwrap();
+    fs::remove_file(&page).unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no <article> found"), "{stderr}");
+}
@@ -0,0 +1,258 @@
+//! Runs the `aoc` binary against a local stub of adventofcode.com, so fetching
+//! and submitting are covered without the network or a real account.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aoc_stub::{Stub, FIXTURES, SESSION};
+use serde_json::Value;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn aoc(stub: &Stub, dir: &Path, session: &str) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .env("AOC_BASE_URL", stub.base_url())
+        .env("AOC_SESSION", session)
+        .env("AOC_INPUT_DIR", dir.join("inputs"))
+        .env("AOC_ANSWERS", dir.join("answers.toml"))
+        .env("AOC_CACHE_DIR", dir.join("cache"));
+    command
+}
+
+#[test]
+fn test_run_all_fetches_sample_inputs() {
+    let stub = Stub::start();
+    let dir = scratch_dir("run_all");
+
+    let output = aoc(&stub, &dir, SESSION)
+        .args(["run", "--all", "--fetch", "--record", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let records: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let answers: Vec<(u64, &str, u64, &str)> = records
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| {
+            (
+                r["day"].as_u64().unwrap(),
+                r["solution"].as_str().unwrap(),
+                r["part"].as_u64().unwrap(),
+                r["answer"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    for expected in [
+        (1, "Day One", 1, "24000"),
+        (1, "Day One", 2, "45000"),
+        (2, "Day Two", 1, "15"),
+        (2, "Day Two", 2, "12"),
+        (3, "Day Three", 1, "157"),
+        (3, "Day Three", 2, "70"),
+        (4, "Day Four", 1, "2"),
+        (4, "Day Four", 2, "4"),
+        (5, "Day Five", 1, "CMZ"),
+        (5, "Day Five", 2, "MCD"),
+        (6, "Day Six", 1, "7"),
+        (6, "Day Six", 2, "19"),
+    ] {
+        assert!(answers.contains(&expected), "missing {expected:?}");
+    }
+
+    let requests = stub.requests();
+    assert_eq!(requests.len(), 6);
+    for (day, request) in (1..).zip(&requests) {
+        assert_eq!(request.path, format!("/2022/day/{day}/input"));
+        assert_eq!(request.cookie.as_deref(), Some("session=stub-session"));
+    }
+
+    let recorded = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert!(
+        recorded.contains("[2022.day5]\npart1 = \"CMZ\""),
+        "{recorded}"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_run_reports_rejected_session() {
+    let stub = Stub::start();
+    let dir = scratch_dir("rejected");
+
+    let output = aoc(&stub, &dir, "not-the-session")
+        .args(["run", "4", "--fetch"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("answered 400"), "{stderr}");
+    assert_eq!(
+        stub.requests()[0].cookie.as_deref(),
+        Some("session=not-the-session")
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_submit_solves_and_records() {
+    let stub = Stub::start();
+    let dir = scratch_dir("submit");
+
+    let output = aoc(&stub, &dir, SESSION)
+        .env("AOC_SUBMISSIONS", dir.join("submissions.toml"))
+        .args(["submit", "3", "1", "--fetch"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 3 Part One: 157 is correct\n"
+    );
+    let requests = stub.requests();
+    assert_eq!(requests[1].path, "/2022/day/3/answer");
+    assert_eq!(requests[1].body, "level=1&answer=157");
+
+    let recorded = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert!(
+        recorded.contains("[2022.day3]\npart1 = \"157\""),
+        "{recorded}"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_submit_never_resends_wrong_answer() {
+    let stub = Stub::start();
+    let dir = scratch_dir("resend");
+    let submit = || {
+        aoc(&stub, &dir, SESSION)
+            .env("AOC_SUBMISSIONS", dir.join("submissions.toml"))
+            .args(["submit", "1", "1", "100"])
+            .output()
+            .unwrap()
+    };
+
+    let first = submit();
+    assert!(!first.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&first.stdout),
+        "Day 1 Part One: 100 is too low\n"
+    );
+
+    let second = submit();
+    assert!(!second.status.success());
+    let stderr = String::from_utf8_lossy(&second.stderr);
+    assert!(
+        stderr.contains("already submitted, it was too low"),
+        "{stderr}"
+    );
+    assert_eq!(stub.requests().len(), 1);
+    let log = fs::read_to_string(dir.join("submissions.toml")).unwrap();
+    assert!(
+        log.contains("[2022.day1.part1]\ntoo_low = [\"100\"]"),
+        "{log}"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_run_other_year() {
+    let stub = Stub::start();
+    let dir = scratch_dir("other_year");
+
+    let output = aoc(&stub, &dir, SESSION)
+        .args(["run", "1", "--year", "2021", "--fetch"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no solution registered for 2021 day 1"),
+        "{stderr}"
+    );
+    assert!(stub.requests().is_empty());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_run_unknown_variant_lists_each_once() {
+    let stub = Stub::start();
+    let dir = scratch_dir("unknown_variant");
+
+    let output = aoc(&stub, &dir, SESSION)
+        .args(["run", "--all", "--variant", "fastest"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(
+            "no variant 'fastest' registered, try one of: default, handwritten, nom, rayon, sequential, wide\n"
+        ),
+        "{stderr}"
+    );
+    assert!(stub.requests().is_empty());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_fetch_goes_through_cache() {
+    let stub = Stub::start();
+    let dir = scratch_dir("cache");
+    let run = |args: &[&str]| aoc(&stub, &dir, SESSION).args(args).output().unwrap();
+
+    assert!(run(&["run", "1", "--fetch"]).status.success());
+    assert!(run(&["run", "1", "--fetch"]).status.success());
+    assert!(run(&["run", "2", "--fetch"]).status.success());
+    assert_eq!(stub.requests().len(), 2);
+
+    let list = run(&["cache", "list"]);
+    let list = String::from_utf8_lossy(&list.stdout);
+    let lines: Vec<&str> = list.lines().collect();
+    assert_eq!(lines.len(), 3, "{list}");
+    assert!(lines[1].contains("2022    1"), "{list}");
+    assert!(lines[1].ends_with("ok"), "{list}");
+
+    let show = run(&["cache", "show", "1"]);
+    assert_eq!(
+        String::from_utf8_lossy(&show.stdout).trim_end(),
+        FIXTURES[0].2.trim_end()
+    );
+
+    let clear = run(&["cache", "clear", "--day", "1"]);
+    assert_eq!(
+        String::from_utf8_lossy(&clear.stdout),
+        "removed 1 cached inputs\n"
+    );
+    assert!(run(&["run", "1", "--fetch"]).status.success());
+    assert_eq!(stub.requests().len(), 3);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,96 @@
+//! Parse and part timings for every day, plus head-to-head groups for days
+//! with more than one variant.
+//!
+//! Inputs come from `inputs/YEAR/dayN.txt`; days without one are skipped.
+
+use std::hint::black_box;
+
+use aoc_common::{local_input, Entry, Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn input_for(year: u16, day: u8) -> Option<String> {
+    let input = local_input(year, day);
+    if input.is_none() {
+        eprintln!("skipping {year} day {day}: no inputs/{year}/day{day}.txt");
+    }
+    input
+}
+
+/// Benchmarks parsing and each part separately.
+fn bench_solution<S: Solution>(c: &mut Criterion) {
+    let Some(input) = input_for(S::YEAR, S::DAY) else {
+        return;
+    };
+    let parsed = S::parse(&input).unwrap_or_else(|err| panic!("{}: {err}", S::NAME));
+
+    let mut group = c.benchmark_group(S::NAME);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&parsed))));
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_solution::<day_1::DayOne>(c);
+    bench_solution::<day_1::DayOneWide>(c);
+    bench_solution::<day_2::DayTwo>(c);
+    bench_solution::<day_2::DayTwoRayon>(c);
+    bench_solution::<day_3::DayThree>(c);
+    bench_solution::<day_4::DayFour>(c);
+    bench_solution::<day_4::DayFourNom>(c);
+    bench_solution::<day_5::DayFive>(c);
+    bench_solution::<day_6::DaySix>(c);
+}
+
+/// Parse plus both parts for each variant of a day, since variants split the
+/// work differently, e.g. the handwritten day 4 parses inside each part.
+fn bench_variants(c: &mut Criterion, variants: &[Entry]) {
+    let Some(first) = variants.first() else {
+        return;
+    };
+    let Some(input) = input_for(first.year, first.day) else {
+        return;
+    };
+
+    let names: Vec<&str> = variants.iter().map(|entry| entry.variant).collect();
+    let mut group = c.benchmark_group(format!(
+        "{} {}: {}",
+        first.year,
+        first.name,
+        names.join(" vs ")
+    ));
+    for entry in variants {
+        group.bench_function(entry.variant, |b| {
+            b.iter(|| (entry.solve)(black_box(&input), &Part::BOTH).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// A head-to-head group for every day with more than one registered variant.
+fn variants(c: &mut Criterion) {
+    let solutions = aoc::registry::solutions();
+    for day in solutions.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        if day.len() > 1 {
+            bench_variants(c, day);
+        }
+    }
+}
+
+fn day_6_marker_search(c: &mut Criterion) {
+    let Some(input) = input_for(2022, 6) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("Day Six: start-of-packet marker");
+    group.bench_function("unrolled registers", |b| {
+        b.iter(|| day_6::part_one(black_box(&input)))
+    });
+    group.bench_function("windows", |b| {
+        b.iter(|| day_6::find_marker(black_box(&input), 4))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, days, variants, day_6_marker_search);
+criterion_main!(benches);
@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Part;
+
+/// Overrides the location of the answers manifest.
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+const HEADER: &str = "# Known answers for our inputs, checked by `aoc run`.\n";
+
+/// How an answer compares to the one recorded in the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    New,
+}
+
+impl Verdict {
+    /// `PASS`, `FAIL` or `NEW`, without the expected answer.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::New => "NEW",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            other => write!(f, "{}", other.label()),
+        }
+    }
+}
+
+/// Known answers keyed by year, day and part, stored as `answers.toml`:
+///
+/// ```toml
+/// [2022.day4]
+/// part1 = "444"
+/// part2 = "801"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    known: BTreeMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    /// Reads the manifest at `path`; a missing file has no answers yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|message| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {message}", path.display()),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| err.to_string())?;
+
+        let mut known = BTreeMap::new();
+        for (year_key, days) in &table {
+            let year = parse_year(year_key)?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| format!("[{year_key}] should be a table of days"))?;
+            for (day_key, parts) in days {
+                let day = parse_key(day_key, "day")?;
+                let parts = parts
+                    .as_table()
+                    .ok_or_else(|| format!("[{year_key}.{day_key}] should be a table"))?;
+                for (part_key, answer) in parts {
+                    let part = parse_key(part_key, "part")?;
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.clone(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        _ => {
+                            return Err(format!(
+                                "{year_key}.{day_key}.{part_key} should be a string or integer"
+                            ))
+                        }
+                    };
+                    known.insert((year, day, part), answer);
+                }
+            }
+        }
+        Ok(Answers { known })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.known
+            .get(&(year, day, part.number()))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::New,
+        }
+    }
+
+    /// Records an answer, returning whether it was new.
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str) -> bool {
+        let key = (year, day, part.number());
+        if self.known.contains_key(&key) {
+            return false;
+        }
+        self.known.insert(key, answer.to_string());
+        true
+    }
+
+    /// Sets an answer, replacing any recorded one.
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: &str) {
+        self.known
+            .insert((year, day, part.number()), answer.to_string());
+    }
+
+    pub fn to_toml(&self) -> String {
+        self.to_toml_with_header(HEADER)
+    }
+
+    /// [`Answers::to_toml`] under a different comment.
+    pub fn to_toml_with_header(&self, header: &str) -> String {
+        let mut out = header.to_string();
+        let mut current = None;
+        for (&(year, day, part), answer) in &self.known {
+            if current != Some((year, day)) {
+                write!(out, "\n[{year}.day{day}]\n").unwrap();
+                current = Some((year, day));
+            }
+            writeln!(out, "part{part} = {}", toml::Value::String(answer.clone())).unwrap();
+        }
+        out
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+}
+
+pub(crate) fn parse_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|num| num.parse().ok())
+        .ok_or_else(|| format!("expected a key like '{prefix}1', found '{key}'"))
+}
+
+pub(crate) fn parse_year(key: &str) -> Result<u16, String> {
+    key.parse()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| format!("expected a year like '2022', found '{key}'"))
+}
+
+/// Location of the manifest: `$AOC_ANSWERS`, else the nearest `answers.toml`
+/// found walking up from the working directory.
+pub fn answers_path() -> PathBuf {
+    if let Some(path) = env::var_os(ANSWERS_VAR) {
+        return PathBuf::from(path);
+    }
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join("answers.toml"))
+                .find(|path| path.is_file())
+        })
+        .unwrap_or_else(|| PathBuf::from("answers.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str =
+        "[2022.day4]\npart1 = 444\npart2 = \"801\"\n\n[2022.day5]\npart1 = \"VJSFHWGFT\"\n";
+
+    #[test]
+    fn test_parse_and_check() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+
+        assert_eq!(answers.check(2022, 4, Part::One, "444"), Verdict::Pass);
+        assert_eq!(answers.check(2022, 4, Part::Two, "801"), Verdict::Pass);
+        assert_eq!(
+            answers.check(2022, 5, Part::One, "CMZ"),
+            Verdict::Fail {
+                expected: "VJSFHWGFT".to_string()
+            }
+        );
+        assert_eq!(answers.check(2022, 5, Part::Two, "LCTQFBVZV"), Verdict::New);
+        assert_eq!(answers.check(2022, 1, Part::One, "24000"), Verdict::New);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_keys() {
+        assert!(Answers::parse("[day4]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[2022.four]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[2022.day4]\none = 1\n").is_err());
+        assert!(Answers::parse("[2022.day4]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let mut answers = Answers::parse(MANIFEST).unwrap();
+
+        assert!(answers.record(2022, 1, Part::One, "24000"));
+        assert!(!answers.record(2022, 4, Part::One, "1"));
+        assert_eq!(answers.get(2022, 4, Part::One), Some("444"));
+
+        let text = answers.to_toml();
+        assert!(text.starts_with(HEADER));
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+    }
+}
@@ -0,0 +1,308 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// Overrides the directory downloaded inputs are cached in.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Identifies one cached input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    pub year: u16,
+    pub day: u8,
+    /// See [`account_id`].
+    pub account: String,
+}
+
+impl CacheKey {
+    pub fn new(year: u16, day: u8, account: &str) -> Self {
+        CacheKey {
+            year,
+            day,
+            account: account.to_string(),
+        }
+    }
+}
+
+/// A cached input as found on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub key: CacheKey,
+    pub path: PathBuf,
+    pub len: u64,
+    /// SHA-256 of the input, as recorded when it was cached.
+    pub hash: Option<String>,
+    /// Whether the input still matches `hash`.
+    pub intact: bool,
+}
+
+/// Downloaded inputs, laid out as `<dir>/<account>/<year>/dayN.txt`, each
+/// next to a `dayN.txt.sha256` holding its hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// The cache in [`cache_dir`].
+    pub fn open() -> Self {
+        Self::new(cache_dir())
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir
+            .join(&key.account)
+            .join(key.year.to_string())
+            .join(format!("day{}.txt", key.day))
+    }
+
+    /// The cached input, if any, after checking it against its hash.
+    pub fn get(&self, key: &CacheKey) -> io::Result<Option<String>> {
+        let path = self.path(key);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if fs::read_to_string(hash_path(&path)).ok().as_deref() != Some(&sha256(&text)) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{} does not match its hash, remove it with `aoc cache clear`",
+                    path.display()
+                ),
+            ));
+        }
+        Ok(Some(text))
+    }
+
+    /// Stores an input; readers never see a partly written file.
+    pub fn put(&self, key: &CacheKey, text: &str) -> io::Result<()> {
+        let path = self.path(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic(&hash_path(&path), &sha256(text))?;
+        write_atomic(&path, text)
+    }
+
+    /// The cached input, else the result of `fetch`, which is then cached.
+    pub fn get_or_fetch(
+        &self,
+        key: &CacheKey,
+        fetch: impl FnOnce() -> io::Result<String>,
+    ) -> io::Result<String> {
+        if let Some(text) = self.get(key)? {
+            return Ok(text);
+        }
+        let text = fetch()?;
+        self.put(key, &text)?;
+        Ok(text)
+    }
+
+    /// Every cached input, sorted by account, year and day.
+    pub fn entries(&self) -> io::Result<Vec<CacheEntry>> {
+        let mut entries = vec![];
+        for account in read_dirs(&self.dir)? {
+            for year in read_dirs(&account)? {
+                let Some(year_num) = file_name(&year).and_then(|y| y.parse().ok()) else {
+                    continue;
+                };
+                for file in fs::read_dir(&year)? {
+                    let path = file?.path();
+                    let Some(day) = file_name(&path)
+                        .and_then(|name| name.strip_prefix("day")?.strip_suffix(".txt"))
+                        .and_then(|day| day.parse().ok())
+                    else {
+                        continue;
+                    };
+                    let text = fs::read_to_string(&path)?;
+                    let hash = fs::read_to_string(hash_path(&path)).ok();
+                    entries.push(CacheEntry {
+                        key: CacheKey::new(year_num, day, file_name(&account).unwrap_or_default()),
+                        len: text.len() as u64,
+                        intact: hash.as_deref() == Some(&sha256(&text)),
+                        hash,
+                        path,
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| {
+            let order = |key: &CacheKey| (key.account.clone(), key.year, key.day);
+            order(&a.key).cmp(&order(&b.key))
+        });
+        Ok(entries)
+    }
+
+    /// Removes the inputs `matches` accepts, returning how many went.
+    pub fn clear(&self, mut matches: impl FnMut(&CacheKey) -> bool) -> io::Result<usize> {
+        let mut removed = 0;
+        for entry in self.entries()? {
+            if matches(&entry.key) {
+                fs::remove_file(&entry.path)?;
+                match fs::remove_file(hash_path(&entry.path)) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                    _ => {}
+                }
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// Directory of the input cache: `$AOC_CACHE_DIR`, else `aoc` in the user's
+/// cache directory.
+pub fn cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    dirs::cache_dir()
+        .map(|dir| dir.join("aoc"))
+        .unwrap_or_else(|| PathBuf::from(".aoc-cache"))
+}
+
+/// Short name for the account behind a session cookie, so inputs for
+/// several accounts can sit side by side without writing the cookie to disk.
+pub fn account_id(session: &str) -> String {
+    let token = session.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    sha256(token)[..12].to_string()
+}
+
+fn sha256(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+fn hash_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".sha256");
+    path.with_file_name(name)
+}
+
+/// Writes to a temporary file beside `path`, then renames it into place.
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".tmp{}", std::process::id()));
+    let tmp = path.with_file_name(name);
+
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+fn read_dirs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    match fs::read_dir(dir) {
+        Ok(read) => Ok(read
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.is_dir())
+            .collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(err),
+    }
+}
+
+fn file_name(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc_cache_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_account_id() {
+        assert_eq!(account_id("session=abc"), account_id("abc\n"));
+        assert_ne!(account_id("abc"), account_id("abd"));
+        assert_eq!(account_id("abc").len(), 12);
+    }
+
+    #[test]
+    fn test_put_get_per_account() {
+        let cache = scratch("accounts");
+        let alice = CacheKey::new(2022, 1, "alice");
+        let bob = CacheKey::new(2022, 1, "bob");
+
+        assert_eq!(cache.get(&alice).unwrap(), None);
+        cache.put(&alice, "1000\n2000").unwrap();
+        cache.put(&bob, "3000").unwrap();
+
+        assert_eq!(cache.get(&alice).unwrap().as_deref(), Some("1000\n2000"));
+        assert_eq!(cache.get(&bob).unwrap().as_deref(), Some("3000"));
+        assert_eq!(cache.get(&CacheKey::new(2021, 1, "alice")).unwrap(), None);
+
+        let fetched = cache.get_or_fetch(&alice, || panic!("cached")).unwrap();
+        assert_eq!(fetched, "1000\n2000");
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_detects_corruption() {
+        let cache = scratch("corrupt");
+        let key = CacheKey::new(2022, 4, "alice");
+        cache.put(&key, "2-4,6-8").unwrap();
+        fs::write(cache.path(&key), "2-4,6-9").unwrap();
+
+        let err = cache.get(&key).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!cache.entries().unwrap()[0].intact);
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_entries_and_clear() {
+        let cache = scratch("clear");
+        for (account, day) in [("bob", 2), ("alice", 5), ("alice", 1)] {
+            cache.put(&CacheKey::new(2022, day, account), "x").unwrap();
+        }
+
+        let keys: Vec<_> = cache
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                CacheKey::new(2022, 1, "alice"),
+                CacheKey::new(2022, 5, "alice"),
+                CacheKey::new(2022, 2, "bob"),
+            ]
+        );
+
+        assert_eq!(cache.clear(|key| key.account == "alice").unwrap(), 2);
+        assert_eq!(cache.entries().unwrap().len(), 1);
+        assert_eq!(cache.clear(|_| true).unwrap(), 1);
+        assert!(cache.entries().unwrap().is_empty());
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
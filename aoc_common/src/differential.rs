@@ -0,0 +1,112 @@
+use crate::{Entry, Part};
+
+/// Solves `input` with every entry, one part at a time, and describes the
+/// first part they answer differently.
+///
+/// Rejecting the input counts as an outcome of its own, so a variant that
+/// errors where another answers is a disagreement, while two variants that
+/// both reject it agree whatever their messages say.
+pub fn cross_check(input: &str, entries: &[Entry]) -> Result<(), String> {
+    for part in Part::BOTH {
+        let outcomes: Vec<(&str, Option<String>)> = entries
+            .iter()
+            .map(|entry| {
+                let answer = (entry.solve)(input, &[part])
+                    .ok()
+                    .and_then(|report| report.parts.into_iter().next())
+                    .map(|solved| solved.answer);
+                (entry.variant, answer)
+            })
+            .collect();
+        if outcomes.windows(2).any(|pair| pair[0].1 != pair[1].1) {
+            let listed: Vec<String> = outcomes
+                .iter()
+                .map(|(variant, answer)| {
+                    format!("{variant} = {}", answer.as_deref().unwrap_or("error"))
+                })
+                .collect();
+            return Err(format!(
+                "part {part}: variants disagree: {}",
+                listed.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Result, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 1;
+        const NAME: &'static str = "Count";
+        const VARIANT: &'static str = "count";
+
+        type Parsed<'a> = &'a str;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+            Ok(input)
+        }
+
+        fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+            Ok(input.lines().count())
+        }
+    }
+
+    /// Agrees with [`Count`] on part one, except that it refuses empty input,
+    /// and counts part two wrong.
+    struct Picky;
+
+    impl Solution for Picky {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 1;
+        const NAME: &'static str = "Picky";
+        const VARIANT: &'static str = "picky";
+
+        type Parsed<'a> = &'a str;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+            match input {
+                "" => Err(Error::invalid("empty input")),
+                _ => Ok(input),
+            }
+        }
+
+        fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+            Ok(input.split('\n').count())
+        }
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let entries = [Entry::of::<Count>(), Entry::of::<Picky>()];
+
+        assert_eq!(cross_check("ab", &entries), Ok(()));
+        assert_eq!(
+            cross_check("ab\n", &entries),
+            Err("part Two: variants disagree: count = 1, picky = 2".to_string())
+        );
+        assert_eq!(
+            cross_check("", &entries),
+            Err("part One: variants disagree: count = 0, picky = error".to_string())
+        );
+        assert_eq!(cross_check("", &entries[..1]), Ok(()));
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Why a [`crate::Solution`] could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Malformed input at a known position, both 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Malformed input whose position is not known yet, see [`Error::at`].
+    Invalid(String),
+    /// Well-formed input that has no answer.
+    NoAnswer(String),
+    /// Well-formed input whose answer does not fit the integer type it is
+    /// worked out in, which a wider type may still answer.
+    Overflow(String),
+}
+
+impl Error {
+    /// Parse error at the start of `at`, which must be a slice of `input`.
+    pub fn parse(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, at);
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Parse error with no position yet, for code that only sees a fragment.
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Error::Invalid(message.into())
+    }
+
+    pub fn no_answer(message: impl Into<String>) -> Self {
+        Error::NoAnswer(message.into())
+    }
+
+    pub fn overflow(message: impl Into<String>) -> Self {
+        Error::Overflow(message.into())
+    }
+
+    /// Pins an [`Error::Invalid`] to the start of `at`, a slice of `input`.
+    pub fn at(self, input: &str, at: &str) -> Self {
+        match self {
+            Error::Invalid(message) => Error::parse(input, at, message),
+            other => other,
+        }
+    }
+
+    /// Parse error at the position nom gave up on.
+    pub fn from_nom(
+        input: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        message: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Error::parse(input, e.input, message),
+            nom::Err::Incomplete(_) => Error::parse(input, &input[input.len()..], message),
+        }
+    }
+}
+
+/// Checks that a `separated_list1(newline, line)` parser left nothing but
+/// trailing whitespace in `rest`.
+///
+/// The list stops quietly at the first line that fails, so that line is parsed
+/// again with `line` to report where it went wrong.
+pub fn ensure_consumed<'a, O>(
+    input: &'a str,
+    rest: &'a str,
+    mut line: impl FnMut(&'a str) -> nom::IResult<&'a str, O>,
+    message: &str,
+) -> Result<()> {
+    let rest = rest.trim_end();
+    if rest.is_empty() {
+        return Ok(());
+    }
+    Err(match rest.strip_prefix('\n').map(&mut line) {
+        Some(Err(err)) => Error::from_nom(input, err, message),
+        Some(Ok((trailing, _))) => Error::parse(input, trailing, message),
+        None => Error::parse(input, rest, message),
+    })
+}
+
+/// Line and column of `at` inside `input`; anything that is not a slice of
+/// `input` is reported at the start.
+fn position(input: &str, at: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (at.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+        .unwrap_or(0);
+
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, column {column}: {message}"),
+            Error::Invalid(message) | Error::NoAnswer(message) | Error::Overflow(message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// An [`Error`] tagged with the day it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayError {
+    pub day: u8,
+    pub error: Error,
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Error::Parse { .. } => write!(f, "day {}, {}", self.day, self.error),
+            _ => write!(f, "day {}: {}", self.day, self.error),
+        }
+    }
+}
+
+impl std::error::Error for DayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2-4,6-8\n2-3,4-5\n5-7,7x9";
+
+    #[test]
+    fn test_position() {
+        assert_eq!(position(INPUT, INPUT), (1, 1));
+        assert_eq!(position(INPUT, &INPUT[4..]), (1, 5));
+        assert_eq!(position(INPUT, &INPUT[8..]), (2, 1));
+        assert_eq!(position(INPUT, &INPUT[21..]), (3, 6));
+        assert_eq!(position(INPUT, &INPUT[INPUT.len()..]), (3, 8));
+        assert_eq!(position(INPUT, &String::from("elsewhere")), (1, 1));
+    }
+
+    #[test]
+    fn test_invalid_at() {
+        let err = Error::invalid("expected '-' after section start").at(INPUT, &INPUT[16..]);
+
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected '-' after section start"
+        );
+        assert_eq!(
+            DayError { day: 4, error: err }.to_string(),
+            "day 4, line 3, column 1: expected '-' after section start"
+        );
+    }
+
+    #[test]
+    fn test_no_answer_keeps_message() {
+        let err = Error::no_answer("no marker").at(INPUT, &INPUT[8..]);
+
+        assert_eq!(err, Error::NoAnswer("no marker".to_string()));
+        assert_eq!(
+            DayError { day: 6, error: err }.to_string(),
+            "day 6: no marker"
+        );
+    }
+
+    #[test]
+    fn test_overflow_is_not_bad_input() {
+        let err = Error::overflow("the total overflows u32").at(INPUT, &INPUT[8..]);
+
+        assert_eq!(err, Error::Overflow("the total overflows u32".to_string()));
+        assert_eq!(
+            DayError { day: 1, error: err }.to_string(),
+            "day 1: the total overflows u32"
+        );
+    }
+}
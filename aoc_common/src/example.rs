@@ -0,0 +1,278 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{solve, Answers, Part, Solution};
+
+/// Overrides the directory holding scraped examples.
+pub const EXAMPLES_DIR_VAR: &str = "AOC_EXAMPLES_DIR";
+
+/// What [`scrape`] found on a saved puzzle page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// From the page's `<title>`, when it was saved with one.
+    pub year: Option<u16>,
+    pub day: u8,
+    pub title: String,
+    /// The first `<pre><code>` block of part one, which both parts use.
+    pub input: String,
+    /// Answer to the example for each part shown on the page.
+    pub answers: Vec<String>,
+}
+
+/// Extracts the example input and answers from a puzzle page saved from
+/// adventofcode.com.
+///
+/// Each part is an `<article class="day-desc">`. Its answer is the last
+/// emphasized code (`<code><em>..</em></code>` or the other way round) outside
+/// a list, as lists tend to walk through intermediate steps; an article that
+/// only emphasizes inside lists answers with the first of them.
+pub fn scrape(html: &str) -> Result<Example, String> {
+    let articles = tag_contents(html, "<article", "</article>");
+    let first = articles
+        .first()
+        .ok_or("no <article> found, is this a puzzle page?")?;
+
+    let heading = tag_contents(first, "<h2", "</h2>")
+        .first()
+        .map(|h2| text(h2))
+        .ok_or("no <h2> heading in the first article")?;
+    let (day, title) = heading
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .strip_prefix("Day ")
+        .and_then(|rest| rest.split_once(": "))
+        .and_then(|(day, title)| Some((day.parse().ok()?, title.to_string())))
+        .ok_or_else(|| {
+            format!("expected a heading like '--- Day 1: Title ---', found '{heading}'")
+        })?;
+
+    let year = tag_contents(html, "<title", "</title>")
+        .first()
+        .and_then(|title| text(title).rsplit(' ').next()?.parse().ok());
+
+    let input = tag_contents(first, "<pre><code>", "</code></pre>")
+        .first()
+        .map(|block| text(block).trim_end_matches('\n').to_string())
+        .ok_or("no <pre><code> example in part one")?;
+
+    let answers = articles
+        .iter()
+        .enumerate()
+        .map(|(index, article)| {
+            answer(article).ok_or_else(|| format!("no emphasized answer in part {}", index + 1))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Example {
+        year,
+        day,
+        title,
+        input,
+        answers,
+    })
+}
+
+/// Last emphasized code outside a list, else the first inside one.
+fn answer(article: &str) -> Option<String> {
+    let mut outside = None;
+    let mut inside = None;
+    for (open, close) in [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ] {
+        let mut from = 0;
+        while let Some(start) = article[from..].find(open).map(|at| from + at) {
+            let body = start + open.len();
+            let Some(end) = article[body..].find(close).map(|at| body + at) else {
+                break;
+            };
+            let before = &article[..start];
+            let in_list = before.matches("<li").count() > before.matches("</li>").count();
+            let found = (start, text(&article[body..end]));
+            if in_list {
+                inside = inside.filter(|(at, _)| *at < start).or(Some(found));
+            } else if outside.as_ref().is_none_or(|(at, _)| *at < start) {
+                outside = Some(found);
+            }
+            from = end;
+        }
+    }
+    outside.or(inside).map(|(_, answer)| answer)
+}
+
+/// Everything between each `open` (up to the end of its tag) and `close`.
+fn tag_contents<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut from = 0;
+    while let Some(start) = html[from..].find(open).map(|at| from + at) {
+        let body = if open.ends_with('>') {
+            start + open.len()
+        } else {
+            match html[start..].find('>') {
+                Some(at) => start + at + 1,
+                None => break,
+            }
+        };
+        let Some(end) = html[body..].find(close).map(|at| body + at) else {
+            break;
+        };
+        found.push(&html[body..end]);
+        from = end + close.len();
+    }
+    found
+}
+
+/// Text of an HTML fragment, without tags and with entities decoded.
+fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Directory holding `YEAR/dayN.txt` examples and their `answers.toml`:
+/// `$AOC_EXAMPLES_DIR`, else the nearest `examples` directory found walking
+/// up from the working directory.
+pub fn examples_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(EXAMPLES_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join("examples"))
+                .find(|dir| dir.is_dir())
+        })
+        .unwrap_or_else(|| PathBuf::from("examples"))
+}
+
+/// Solves the scraped example for `S` and checks it against the page's
+/// answers, panicking on any difference. Used by each day's generated
+/// `tests/example.rs`.
+pub fn assert_example<S: Solution>() {
+    let dir = examples_dir();
+    let path = dir
+        .join(S::YEAR.to_string())
+        .join(format!("day{}.txt", S::DAY));
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("{}: {err}, run `aoc scrape` first", path.display()));
+    let answers = Answers::load(&dir.join("answers.toml")).unwrap_or_else(|err| panic!("{err}"));
+
+    let parts: Vec<Part> = Part::BOTH
+        .into_iter()
+        .filter(|part| answers.get(S::YEAR, S::DAY, *part).is_some())
+        .collect();
+    assert!(
+        !parts.is_empty(),
+        "no example answers for {} day {}",
+        S::YEAR,
+        S::DAY
+    );
+
+    let report = solve::<S>(input.trim_end(), &parts).unwrap_or_else(|err| panic!("{err}"));
+    for part in &report.parts {
+        assert_eq!(
+            Some(part.answer.as_str()),
+            answers.get(S::YEAR, S::DAY, part.part),
+            "{} part {} on the example",
+            S::NAME,
+            part.part
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head>
+<title>Day 6 - Advent of Code 2022</title>
+</head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 6: Tuning Trouble ---</h2><p>For example, suppose you receive the following datastream buffer:</p>
+<pre><code>mjqjpqmgbljsphdztnvjfqwrcgsmlb
+</code></pre>
+<p>After the first three characters (<code>mjq</code>) have been received, there haven't been enough characters received yet to find the marker. The first time a marker could occur is after the fourth character is received, making the most recent four characters <code>mjqj</code>.</p>
+<p>In this example, the first start-of-packet marker is complete after <code><em>7</em></code> characters have been processed.</p>
+<p>Here are a few more examples:</p>
+<ul>
+<li><code>bvwbjplbgvbhsrlpgdmjqwftvncz</code>: first marker after character <code><em>5</em></code></li>
+<li><code>nppdvjthqldpwncqszvftbrmjlhg</code>: first marker after character <code><em>6</em></code></li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>1361</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Here are the first positions of start-of-message markers for all of the above examples:</p>
+<ul>
+<li><code>mjqjpqmgbljsphdztnvjfqwrcgsmlb</code>: first marker after character <code><em>19</em></code></li>
+<li><code>bvwbjplbgvbhsrlpgdmjqwftvncz</code>: first marker after character <code><em>23</em></code></li>
+</ul>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_scrape() {
+        let example = scrape(PAGE).unwrap();
+
+        assert_eq!(example.year, Some(2022));
+        assert_eq!(example.day, 6);
+        assert_eq!(example.title, "Tuning Trouble");
+        assert_eq!(example.input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(example.answers, ["7", "19"]);
+    }
+
+    #[test]
+    fn test_scrape_part_one_only() {
+        let page = r#"<main><article class="day-desc"><h2>--- Day 5: Supply Stacks ---</h2>
+<pre><code>    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+</code></pre>
+<p>The Elves just need to know which crate will end up on top of each stack; in this example, the top crates are <code>C</code> in stack 1, <code>M</code> in stack 2, and <code>Z</code> in stack 3, so you should combine these together and give the Elves the message <em><code>CMZ</code></em>.</p>
+<p>Is 3 &lt; 4 &amp;&amp; 2 &gt; 1?</p>
+</article></main>"#;
+        let example = scrape(page).unwrap();
+
+        assert_eq!(example.year, None);
+        assert_eq!(example.day, 5);
+        assert!(example.input.starts_with("    [D]\n[N] [C]\n"));
+        assert!(example.input.ends_with("move 1 from 2 to 1"));
+        assert_eq!(example.answers, ["CMZ"]);
+    }
+
+    #[test]
+    fn test_scrape_errors() {
+        assert!(scrape("<html></html>").is_err());
+        assert!(scrape("<article><h2>Day one</h2></article>").is_err());
+        assert!(scrape("<article><h2>--- Day 1: X ---</h2><p>no example</p></article>").is_err());
+        assert!(scrape(
+            "<article><h2>--- Day 1: X ---</h2><pre><code>1\n</code></pre><p>none</p></article>"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_text_decodes_entities() {
+        assert_eq!(text("<em>a</em> &lt;b&gt; &amp;lt;"), "a <b> &lt;");
+    }
+}
@@ -0,0 +1,179 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::{account_id, resolve_session, Cache, CacheKey};
+
+/// Overrides the directory searched for local `YEAR/dayN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Overrides the site inputs are downloaded from, e.g. to point at a local stub.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as the site asks of automated tools.
+pub(crate) const USER_AGENT: &str = "github.com/scottfones/aoc_2022 by scottfones";
+
+/// Where a day's input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/YEAR/dayN.txt`, falling back to the network only when `fetch`
+    /// is set.
+    /// `session` is a cookie given on the command line, see [`resolve_session`].
+    Local {
+        fetch: bool,
+        session: Option<String>,
+    },
+    /// An explicit file.
+    File(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Builds a source from an optional `--input` value, where `-` means stdin.
+    pub fn from_arg(input: Option<&str>, fetch: bool, session: Option<&str>) -> Self {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::Local {
+                fetch,
+                session: session.map(str::to_string),
+            },
+        }
+    }
+
+    /// Reads the input for the given puzzle, trimmed of trailing whitespace.
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        let input = match self {
+            InputSource::File(path) => fs::read_to_string(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            InputSource::Local { fetch, session } => match local_input(year, day) {
+                Some(input) => input,
+                None if *fetch => fetch_input(year, day, &resolve_session(session.as_deref())?)?,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!(
+                            "no input at {}, pass --input <path>, or --fetch to download it",
+                            local_input_path(year, day).display()
+                        ),
+                    ))
+                }
+            },
+        };
+        Ok(input.trim_end().to_string())
+    }
+}
+
+/// Directory holding local inputs: `$AOC_INPUT_DIR`, else the nearest
+/// `inputs` directory found walking up from the working directory.
+pub fn input_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join("inputs"))
+                .find(|dir| dir.is_dir())
+        })
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// Path of the local input file for the given puzzle.
+pub fn local_input_path(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Local input for the given puzzle, if one has been saved, trimmed of
+/// trailing whitespace.
+pub fn local_input(year: u16, day: u8) -> Option<String> {
+    fs::read_to_string(local_input_path(year, day))
+        .ok()
+        .map(|input| input.trim_end().to_string())
+}
+
+/// Site to download from: `$AOC_BASE_URL`, else adventofcode.com.
+pub fn base_url() -> String {
+    env::var(BASE_URL_VAR)
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| BASE_URL.to_string())
+}
+
+/// Input url for the given puzzle on the site at `base_url`.
+pub fn input_url(base_url: &str, year: u16, day: u8) -> String {
+    format!("{base_url}/{year}/day/{day}/input")
+}
+
+/// Downloads the input for the given puzzle from [`base_url`], from the
+/// [`Cache`] when the account behind `session` already has it.
+pub fn fetch_input(year: u16, day: u8, session: &str) -> io::Result<String> {
+    let key = CacheKey::new(year, day, &account_id(session));
+    Cache::open().get_or_fetch(&key, || fetch_input_from(&base_url(), year, day, session))
+}
+
+/// Downloads the input for the given puzzle from the site at `base_url`,
+/// bypassing the cache.
+pub fn fetch_input_from(base_url: &str, year: u16, day: u8, session: &str) -> io::Result<String> {
+    let url = input_url(base_url, year, day);
+    match ureq::get(&url)
+        .set("Cookie", session)
+        .set("User-Agent", USER_AGENT)
+        .call()
+    {
+        Ok(response) => Ok(response.into_string()?.trim_end().to_string()),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(io::Error::other(format!(
+                "{url} answered {code}: {}",
+                body.trim()
+            )))
+        }
+        Err(err) => Err(io::Error::other(format!("{url}: {err}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(
+            InputSource::from_arg(Some("-"), false, None),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some("day5.txt"), true, None),
+            InputSource::File(PathBuf::from("day5.txt"))
+        );
+        assert_eq!(
+            InputSource::from_arg(None, true, Some("abc")),
+            InputSource::Local {
+                fetch: true,
+                session: Some("abc".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_file_trims_trailing_whitespace() {
+        let path = env::temp_dir().join(format!("aoc_common_input_{}.txt", std::process::id()));
+        fs::write(&path, "1000\n2000\n\n3000\n\n").unwrap();
+
+        let input = InputSource::File(path.clone()).read(2022, 1).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(input, "1000\n2000\n\n3000");
+    }
+}
@@ -0,0 +1,55 @@
+//! Shared plumbing for the daily puzzle crates.
+//!
+//! A day implements [`Solution`] and hands itself to [`run`], which takes care
+//! of reading the input, timing each step and printing the answers.
+
+mod answers;
+mod cache;
+mod differential;
+mod error;
+mod example;
+mod input;
+mod runner;
+mod session;
+mod submit;
+
+pub use answers::{answers_path, Answers, Verdict, ANSWERS_VAR};
+pub use cache::{account_id, cache_dir, Cache, CacheEntry, CacheKey, CACHE_DIR_VAR};
+pub use differential::cross_check;
+pub use error::{ensure_consumed, DayError, Error, Result};
+pub use example::{assert_example, examples_dir, scrape, Example, EXAMPLES_DIR_VAR};
+pub use input::{
+    base_url, fetch_input, fetch_input_from, input_dir, input_url, local_input, local_input_path,
+    InputSource, BASE_URL_VAR, INPUT_DIR_VAR,
+};
+pub use runner::{run, solve, Entry, Part, PartReport, Report};
+pub use session::{resolve_session, session_file, SESSION_VAR};
+pub use submit::{
+    answer_url, parse_reply, post_answer, submissions_path, submit, submit_to, Feedback, Refusal,
+    Reply, Submissions, SubmitError, SUBMISSIONS_VAR,
+};
+
+use std::fmt::Display;
+
+/// One day of puzzles, split into a parsing step and the two parts.
+pub trait Solution {
+    /// Puzzle year, used with the day to locate the input.
+    const YEAR: u16;
+    /// Puzzle day, used to locate the input.
+    const DAY: u8;
+    /// Label used when printing timings, e.g. "Day Four (Nom)".
+    const NAME: &'static str;
+    /// Which of the day's implementations this is, e.g. "nom", when a day has
+    /// several; runners select and cross-check them by this name.
+    const VARIANT: &'static str = "default";
+
+    /// Parsed form of the input, free to borrow from the raw text.
+    type Parsed<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the input, reporting malformed text with its line and column.
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Self::PartOne>;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Self::PartTwo>;
+}
@@ -0,0 +1,251 @@
+use std::env;
+use std::fmt;
+use std::process;
+use std::time::{Duration, Instant};
+
+use crate::{DayError, InputSource, Result, Solution};
+
+const USAGE: &str = "usage: [--input <path>|-] [--fetch] [--session <cookie>]";
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "One"),
+            Part::Two => write!(f, "Two"),
+        }
+    }
+}
+
+/// Answer to a single part, rendered to text, and the time it took.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// Outcome of solving some parts of a [`Solution`] against one input.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+}
+
+/// Parses `input` and solves the requested parts, timing each step.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Report, DayError> {
+    let tag = |error| DayError { day: S::DAY, error };
+
+    let t_parse = Instant::now();
+    let parsed = S::parse(input).map_err(tag)?;
+    let parse = t_parse.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let t_part = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed).map_err(tag)?.to_string(),
+                Part::Two => S::part_two(&parsed).map_err(tag)?.to_string(),
+            };
+            Ok(PartReport {
+                part,
+                answer,
+                time: t_part.elapsed(),
+            })
+        })
+        .collect::<Result<_, DayError>>()?;
+
+    Ok(Report { parse, parts })
+}
+
+/// Type-erased handle on a [`Solution`], so runners can keep a list of days.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub variant: &'static str,
+    pub solve: fn(&str, &[Part]) -> Result<Report, DayError>,
+}
+
+impl Entry {
+    pub fn of<S: Solution>() -> Self {
+        Entry {
+            year: S::YEAR,
+            day: S::DAY,
+            name: S::NAME,
+            variant: S::VARIANT,
+            solve: solve::<S>,
+        }
+    }
+}
+
+/// Reads the day's input, solves it and prints the answers and timings.
+///
+/// The input comes from `inputs/YEAR/dayN.txt` unless `--input <path>` (or `-` for
+/// stdin) is given; `--fetch` allows downloading it when no local copy exists,
+/// using the cookie from `--session` or [`crate::resolve_session`].
+pub fn run<S: Solution>() {
+    let t_start = Instant::now();
+
+    let source = match source_from_args(env::args().skip(1)) {
+        Ok(source) => source,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    let input = match source.read(S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}: {err}", S::NAME);
+            process::exit(1);
+        }
+    };
+    let report = match solve::<S>(&input, &Part::BOTH) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    for PartReport { part, answer, .. } in &report.parts {
+        println!("Part {part}: {answer}");
+    }
+
+    let t_total = t_start.elapsed().as_micros();
+    println!("{} Time: {t_total} microseconds", S::NAME);
+}
+
+fn source_from_args(mut args: impl Iterator<Item = String>) -> Result<InputSource, String> {
+    let mut input = None;
+    let mut fetch = false;
+    let mut session = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(args.next().ok_or("--input needs a value")?),
+            "--fetch" => fetch = true,
+            "--session" => session = Some(args.next().ok_or("--session needs a value")?),
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+    Ok(InputSource::from_arg(
+        input.as_deref(),
+        fetch,
+        session.as_deref(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 0;
+        const NAME: &'static str = "Sum";
+
+        type Parsed<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(parsed: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+            parsed
+                .iter()
+                .map(|line| {
+                    line.parse::<usize>()
+                        .map_err(|_| Error::invalid(format!("not a number: '{line}'")))
+                })
+                .sum()
+        }
+
+        fn part_two(parsed: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+            Ok(parsed.concat())
+        }
+    }
+
+    #[test]
+    fn test_solve_renders_answers() {
+        let report = solve::<Sum>("1\n2\n3", &Part::BOTH).unwrap();
+
+        assert_eq!(report.parts[0].answer, "6");
+        assert_eq!(report.parts[1].answer, "123");
+        assert!(report.total() >= report.parse);
+    }
+
+    #[test]
+    fn test_solve_selected_part() {
+        let report = (Entry::of::<Sum>().solve)("1\n2\n3", &[Part::Two]).unwrap();
+
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, Part::Two);
+        assert_eq!(report.parts[0].answer, "123");
+    }
+
+    #[test]
+    fn test_solve_tags_errors_with_day() {
+        let err = solve::<Sum>("1\nx", &Part::BOTH).unwrap_err();
+
+        assert_eq!(err.day, 0);
+        assert_eq!(err.to_string(), "day 0: not a number: 'x'");
+    }
+
+    #[test]
+    fn test_source_from_args() {
+        let args = |list: &[&str]| list.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            source_from_args(args(&[]).into_iter()),
+            Ok(InputSource::Local {
+                fetch: false,
+                session: None
+            })
+        );
+        assert_eq!(
+            source_from_args(args(&["--fetch", "--session", "abc"]).into_iter()),
+            Ok(InputSource::Local {
+                fetch: true,
+                session: Some("abc".to_string())
+            })
+        );
+        assert_eq!(
+            source_from_args(args(&["--fetch", "--input", "-"]).into_iter()),
+            Ok(InputSource::Stdin)
+        );
+        assert!(source_from_args(args(&["--input"]).into_iter()).is_err());
+        assert!(source_from_args(args(&["--session"]).into_iter()).is_err());
+        assert!(source_from_args(args(&["5"]).into_iter()).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Config file holding the session cookie, `<config dir>/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc").join("session"))
+}
+
+/// Resolves the session cookie from, in order, the `--session` flag, the
+/// `AOC_SESSION` variable and the config file.
+///
+/// The value may be the bare token or `session=<token>`; it is returned in the
+/// latter form, as sent in the `Cookie` header.
+pub fn resolve_session(flag: Option<&str>) -> io::Result<String> {
+    let from_env = || env::var(SESSION_VAR).ok();
+    let from_file = || session_file().and_then(|path| fs::read_to_string(path).ok());
+
+    first_session(flag, from_env, from_file).ok_or_else(|| {
+        let file = session_file()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "the config dir".to_string());
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no session cookie: pass --session, set {SESSION_VAR}, or save it to {file}"),
+        )
+    })
+}
+
+fn first_session(
+    flag: Option<&str>,
+    from_env: impl FnOnce() -> Option<String>,
+    from_file: impl FnOnce() -> Option<String>,
+) -> Option<String> {
+    flag.map(str::to_string)
+        .and_then(normalize)
+        .or_else(|| from_env().and_then(normalize))
+        .or_else(|| from_file().and_then(normalize))
+}
+
+fn normalize(value: String) -> Option<String> {
+    let token = value.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    (!token.is_empty()).then(|| format!("session={token}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("abc123\n".to_string()),
+            Some("session=abc123".to_string())
+        );
+        assert_eq!(
+            normalize("session=abc123".to_string()),
+            Some("session=abc123".to_string())
+        );
+        assert_eq!(normalize(" \n".to_string()), None);
+        assert_eq!(normalize("session=".to_string()), None);
+    }
+
+    #[test]
+    fn test_first_session_precedence() {
+        let env = || Some("from_env".to_string());
+        let file = || Some("from_file".to_string());
+        let none = || None;
+
+        assert_eq!(
+            first_session(Some("flag"), env, file),
+            Some("session=flag".to_string())
+        );
+        assert_eq!(
+            first_session(None, env, file),
+            Some("session=from_env".to_string())
+        );
+        assert_eq!(
+            first_session(None, none, file),
+            Some("session=from_file".to_string())
+        );
+        assert_eq!(first_session(Some(""), || Some(String::new()), none), None);
+    }
+}
@@ -0,0 +1,616 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answers::{parse_key, parse_year};
+use crate::input::USER_AGENT;
+use crate::{answers_path, base_url, Part};
+
+/// Overrides the location of the submission log.
+pub const SUBMISSIONS_VAR: &str = "AOC_SUBMISSIONS";
+
+const HEADER: &str = "# Answers sent to adventofcode.com, so wrong guesses are never sent twice.\n";
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// Sent too soon after a wrong answer, so it was not checked.
+    Wait(Duration),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow => write!(f, "too low"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::Wait(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Feedback::WrongLevel => write!(f, "already solved or not unlocked"),
+        }
+    }
+}
+
+/// The site's answer page, boiled down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reply {
+    pub feedback: Feedback,
+    /// How long before the next answer will be checked.
+    pub cooldown: Option<Duration>,
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The same answer was already sent and judged wrong.
+    Repeated(Feedback),
+    /// A smaller answer was already too high.
+    AboveTooHigh(String),
+    /// A larger answer was already too low.
+    BelowTooLow(String),
+    /// The site asked us to wait after the last wrong answer.
+    Cooldown(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Repeated(feedback) => write!(f, "already submitted, it was {feedback}"),
+            Refusal::AboveTooHigh(guess) => write!(f, "too high, {guess} already was"),
+            Refusal::BelowTooLow(guess) => write!(f, "too low, {guess} already was"),
+            Refusal::Cooldown(wait) => {
+                write!(f, "wait {}s after the last wrong answer", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// Error from [`submit`]: either the log refused the answer or the request
+/// failed.
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Io(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted: {refusal}"),
+            SubmitError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Io(err)
+    }
+}
+
+/// Wrong guesses and cooldowns for one part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Guesses {
+    too_high: Vec<String>,
+    too_low: Vec<String>,
+    wrong: Vec<String>,
+    /// Seconds since the epoch before which nothing should be sent.
+    wait_until: Option<u64>,
+}
+
+/// Answers already submitted, keyed by year, day and part, stored as
+/// `submissions.toml`:
+///
+/// ```toml
+/// [2022.day1.part1]
+/// too_high = ["72000"]
+/// too_low = ["24000"]
+/// wait_until = 1670000060
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    parts: BTreeMap<(u16, u8, u8), Guesses>,
+}
+
+impl Submissions {
+    /// Reads the log at `path`; a missing file has no submissions yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|message| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {message}", path.display()),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| err.to_string())?;
+
+        let mut parts = BTreeMap::new();
+        for (year_key, days) in &table {
+            let year = parse_year(year_key)?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| format!("[{year_key}] should be a table of days"))?;
+            for (day_key, day_table) in days {
+                let day = parse_key(day_key, "day")?;
+                let day_table = day_table
+                    .as_table()
+                    .ok_or_else(|| format!("[{year_key}.{day_key}] should be a table"))?;
+                for (part_key, fields) in day_table {
+                    let part = parse_key(part_key, "part")?;
+                    let name = format!("{year_key}.{day_key}.{part_key}");
+                    let fields = fields
+                        .as_table()
+                        .ok_or_else(|| format!("[{name}] should be a table"))?;
+                    let list = |field: &str| -> Result<Vec<String>, String> {
+                        match fields.get(field) {
+                            None => Ok(vec![]),
+                            Some(toml::Value::Array(items)) => items
+                                .iter()
+                                .map(|item| match item {
+                                    toml::Value::String(answer) => Ok(answer.clone()),
+                                    toml::Value::Integer(answer) => Ok(answer.to_string()),
+                                    _ => Err(format!("{name}.{field} should hold strings")),
+                                })
+                                .collect(),
+                            Some(_) => Err(format!("{name}.{field} should be an array")),
+                        }
+                    };
+                    let wait_until = match fields.get("wait_until") {
+                        None => None,
+                        Some(toml::Value::Integer(secs)) if *secs >= 0 => Some(*secs as u64),
+                        Some(_) => return Err(format!("{name}.wait_until should be a timestamp")),
+                    };
+                    let guesses = Guesses {
+                        too_high: list("too_high")?,
+                        too_low: list("too_low")?,
+                        wrong: list("wrong")?,
+                        wait_until,
+                    };
+                    parts.insert((year, day, part), guesses);
+                }
+            }
+        }
+        Ok(Submissions { parts })
+    }
+
+    /// Checks whether `answer` is worth sending at `now`.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: SystemTime,
+    ) -> Result<(), Refusal> {
+        let Some(guesses) = self.parts.get(&(year, day, part.number())) else {
+            return Ok(());
+        };
+
+        for (list, feedback) in [
+            (&guesses.too_high, Feedback::TooHigh),
+            (&guesses.too_low, Feedback::TooLow),
+            (&guesses.wrong, Feedback::Wrong),
+        ] {
+            if list.iter().any(|guess| guess == answer) {
+                return Err(Refusal::Repeated(feedback));
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let numbers = |list: &[String]| -> Vec<(i128, String)> {
+                list.iter()
+                    .filter_map(|guess| Some((guess.parse().ok()?, guess.clone())))
+                    .collect()
+            };
+            if let Some((_, guess)) = numbers(&guesses.too_high)
+                .into_iter()
+                .filter(|(high, _)| value >= *high)
+                .min()
+            {
+                return Err(Refusal::AboveTooHigh(guess));
+            }
+            if let Some((_, guess)) = numbers(&guesses.too_low)
+                .into_iter()
+                .filter(|(low, _)| value <= *low)
+                .max()
+            {
+                return Err(Refusal::BelowTooLow(guess));
+            }
+        }
+
+        let now = unix_secs(now);
+        match guesses.wait_until {
+            Some(until) if until > now => Err(Refusal::Cooldown(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remembers the site's reply to `answer`, sent at `now`.
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+        reply: &Reply,
+        now: SystemTime,
+    ) {
+        let guesses = self.parts.entry((year, day, part.number())).or_default();
+        let list = match reply.feedback {
+            Feedback::TooHigh => Some(&mut guesses.too_high),
+            Feedback::TooLow => Some(&mut guesses.too_low),
+            Feedback::Wrong => Some(&mut guesses.wrong),
+            Feedback::Correct | Feedback::Wait(_) | Feedback::WrongLevel => None,
+        };
+        if let Some(list) = list {
+            if !list.iter().any(|guess| guess == answer) {
+                list.push(answer.to_string());
+            }
+        }
+        guesses.wait_until = reply.cooldown.map(|wait| unix_secs(now) + wait.as_secs());
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = HEADER.to_string();
+        for (&(year, day, part), guesses) in &self.parts {
+            write!(out, "\n[{year}.day{day}.part{part}]\n").unwrap();
+            for (field, list) in [
+                ("too_high", &guesses.too_high),
+                ("too_low", &guesses.too_low),
+                ("wrong", &guesses.wrong),
+            ] {
+                if !list.is_empty() {
+                    let values: toml::value::Array =
+                        list.iter().cloned().map(toml::Value::String).collect();
+                    writeln!(out, "{field} = {}", toml::Value::Array(values)).unwrap();
+                }
+            }
+            if let Some(until) = guesses.wait_until {
+                writeln!(out, "wait_until = {until}").unwrap();
+            }
+        }
+        out
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or_default()
+}
+
+/// Location of the log: `$AOC_SUBMISSIONS`, else `submissions.toml` next to
+/// the answers manifest.
+pub fn submissions_path() -> PathBuf {
+    match env::var_os(SUBMISSIONS_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => answers_path().with_file_name("submissions.toml"),
+    }
+}
+
+/// Answer url for the given puzzle on the site at `base_url`.
+pub fn answer_url(base_url: &str, year: u16, day: u8) -> String {
+    format!("{base_url}/{year}/day/{day}/answer")
+}
+
+/// Posts an answer to the site at `base_url` and reads its reply, without
+/// consulting any log.
+pub fn post_answer(
+    base_url: &str,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    session: &str,
+) -> io::Result<Reply> {
+    let url = answer_url(base_url, year, day);
+    let level = part.number().to_string();
+    let response = ureq::post(&url)
+        .set("Cookie", session)
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &level), ("answer", answer)]);
+
+    let body = match response {
+        Ok(response) => response.into_string()?,
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            return Err(io::Error::other(format!(
+                "{url} answered {code}: {}",
+                article_text(&body)
+            )));
+        }
+        Err(err) => return Err(io::Error::other(format!("{url}: {err}"))),
+    };
+
+    parse_reply(&body).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{url}: unrecognised reply '{}'", article_text(&body)),
+        )
+    })
+}
+
+/// Submits an answer to [`base_url`] unless `log` already rules it out, and
+/// records the reply in `log`.
+pub fn submit(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    session: &str,
+    log: &mut Submissions,
+) -> Result<Feedback, SubmitError> {
+    submit_to(
+        &base_url(),
+        year,
+        day,
+        part,
+        answer,
+        session,
+        log,
+        SystemTime::now(),
+    )
+}
+
+/// [`submit`] against the site at `base_url`, as of `now`.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_to(
+    base_url: &str,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    session: &str,
+    log: &mut Submissions,
+    now: SystemTime,
+) -> Result<Feedback, SubmitError> {
+    log.check(year, day, part, answer, now)
+        .map_err(SubmitError::Refused)?;
+    let reply = post_answer(base_url, year, day, part, answer, session)?;
+    log.record(year, day, part, answer, &reply, now);
+    Ok(reply.feedback)
+}
+
+/// Reads the `<article>` of an answer page.
+pub fn parse_reply(html: &str) -> Option<Reply> {
+    let text = article_text(html);
+    let lower = text.to_lowercase();
+
+    let feedback = if lower.contains("that's the right answer") {
+        Feedback::Correct
+    } else if lower.contains("that's not the right answer") {
+        if lower.contains("too high") {
+            Feedback::TooHigh
+        } else if lower.contains("too low") {
+            Feedback::TooLow
+        } else {
+            Feedback::Wrong
+        }
+    } else if lower.contains("you gave an answer too recently") {
+        Feedback::Wait(time_left(&lower)?)
+    } else if lower.contains("you don't seem to be solving the right level") {
+        Feedback::WrongLevel
+    } else {
+        return None;
+    };
+
+    let cooldown = match feedback {
+        Feedback::Wait(wait) => Some(wait),
+        _ => wait_before_retry(&lower),
+    };
+    Some(Reply { feedback, cooldown })
+}
+
+/// Text of the page's `<article>`, or the whole page, without tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = html[start..].find("</article>")?;
+            Some(&html[start..start + end])
+        })
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 1m 4s left to wait."
+fn time_left(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("you have ")? + "you have ".len();
+    text[start..end]
+        .split_whitespace()
+        .try_fold(0, |secs, token| {
+            let (num, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let num: u64 = num.parse().ok()?;
+            match unit {
+                "h" => Some(secs + num * 3600),
+                "m" => Some(secs + num * 60),
+                "s" => Some(secs + num),
+                _ => None,
+            }
+        })
+        .map(Duration::from_secs)
+}
+
+/// "Please wait one minute before trying again."
+fn wait_before_retry(text: &str) -> Option<Duration> {
+    let start = text.find("please wait ")? + "please wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let count = words.next()?;
+    let minutes = match count {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        num => num.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><main><article><p>{article}</p></article></main></html>")
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let reply = |article| parse_reply(&page(article));
+
+        assert_eq!(
+            reply("That's the right answer!  You are <em>one gold star</em> closer."),
+            Some(Reply {
+                feedback: Feedback::Correct,
+                cooldown: None
+            })
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too high.  If you're stuck, read the <a href=\"/about\">about page</a>.  Please wait one minute before trying again."),
+            Some(Reply {
+                feedback: Feedback::TooHigh,
+                cooldown: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."),
+            Some(Reply {
+                feedback: Feedback::TooLow,
+                cooldown: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            reply("That's not the right answer.  Please wait one minute before trying again.")
+                .map(|r| r.feedback),
+            Some(Feedback::Wrong)
+        );
+        assert_eq!(
+            reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait."),
+            Some(Reply {
+                feedback: Feedback::Wait(Duration::from_secs(64)),
+                cooldown: Some(Duration::from_secs(64))
+            })
+        );
+        assert_eq!(
+            reply("You don't seem to be solving the right level.  Did you already complete it?")
+                .map(|r| r.feedback),
+            Some(Feedback::WrongLevel)
+        );
+        assert_eq!(reply("Something else entirely."), None);
+    }
+
+    #[test]
+    fn test_check_refuses_known_wrong_answers() {
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut log = Submissions::default();
+        let too_high = Reply {
+            feedback: Feedback::TooHigh,
+            cooldown: Some(Duration::from_secs(60)),
+        };
+        log.record(2022, 1, Part::One, "500", &too_high, now);
+
+        assert_eq!(
+            log.check(2022, 1, Part::One, "500", now),
+            Err(Refusal::Repeated(Feedback::TooHigh))
+        );
+        assert_eq!(
+            log.check(2022, 1, Part::One, "900", now),
+            Err(Refusal::AboveTooHigh("500".to_string()))
+        );
+        assert_eq!(
+            log.check(2022, 1, Part::One, "400", now),
+            Err(Refusal::Cooldown(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            log.check(2022, 1, Part::One, "400", now + Duration::from_secs(60)),
+            Ok(())
+        );
+        assert_eq!(log.check(2022, 1, Part::Two, "500", now), Ok(()));
+
+        let too_low = Reply {
+            feedback: Feedback::TooLow,
+            cooldown: None,
+        };
+        log.record(2022, 1, Part::One, "100", &too_low, now);
+        assert_eq!(
+            log.check(2022, 1, Part::One, "50", now),
+            Err(Refusal::BelowTooLow("100".to_string()))
+        );
+        assert_eq!(log.check(2022, 1, Part::One, "300", now), Ok(()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut log = Submissions::default();
+        for (answer, feedback) in [("9", Feedback::TooHigh), ("1", Feedback::TooLow)] {
+            let reply = Reply {
+                feedback,
+                cooldown: Some(Duration::from_secs(60)),
+            };
+            log.record(2022, 2, Part::Two, answer, &reply, now);
+        }
+        log.record(
+            2022,
+            5,
+            Part::One,
+            "ABC",
+            &Reply {
+                feedback: Feedback::Wrong,
+                cooldown: None,
+            },
+            now,
+        );
+
+        let text = log.to_toml();
+        assert!(text.starts_with(HEADER));
+        assert!(text.contains(
+            "[2022.day2.part2]\ntoo_high = [\"9\"]\ntoo_low = [\"1\"]\nwait_until = 1060\n"
+        ));
+        assert_eq!(Submissions::parse(&text).unwrap(), log);
+        assert!(Submissions::parse("[2022.day1.part1]\ntoo_high = \"9\"\n").is_err());
+        assert!(Submissions::parse("[day1.part1]\ntoo_high = [\"9\"]\n").is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_common::fetch_input_from;
+use aoc_stub::{Stub, FIXTURES, SESSION};
+
+#[test]
+fn test_fetch_sends_session_cookie() {
+    let stub = Stub::start();
+
+    let input = fetch_input_from(stub.base_url(), 2022, 4, &format!("session={SESSION}")).unwrap();
+
+    assert_eq!(input, FIXTURES[3].2.trim_end());
+    let requests = stub.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/4/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=stub-session"));
+}
+
+#[test]
+fn test_fetch_rejects_wrong_session() {
+    let stub = Stub::start();
+
+    let err = fetch_input_from(stub.base_url(), 2022, 4, "session=someone-else").unwrap_err();
+
+    assert!(err.to_string().contains("answered 400"), "{err}");
+    assert!(err.to_string().contains("Please log in"), "{err}");
+}
+
+#[test]
+fn test_fetch_locked_day() {
+    let stub = Stub::start();
+
+    let err =
+        fetch_input_from(stub.base_url(), 2022, 25, &format!("session={SESSION}")).unwrap_err();
+
+    assert!(err.to_string().contains("answered 404"), "{err}");
+}
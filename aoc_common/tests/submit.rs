@@ -0,0 +1,163 @@
+use std::time::{Duration, SystemTime};
+
+use aoc_common::{submit_to, Feedback, Part, Refusal, Submissions, SubmitError};
+use aoc_stub::{Stub, SESSION};
+
+fn session() -> String {
+    format!("session={SESSION}")
+}
+
+#[test]
+fn test_submit_correct_answer() {
+    let stub = Stub::start();
+    let mut log = Submissions::default();
+    let now = SystemTime::now();
+
+    let feedback = submit_to(
+        stub.base_url(),
+        2022,
+        5,
+        Part::One,
+        "CMZ",
+        &session(),
+        &mut log,
+        now,
+    );
+
+    assert_eq!(feedback.unwrap(), Feedback::Correct);
+    let requests = stub.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/5/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=stub-session"));
+    assert_eq!(requests[0].body, "level=1&answer=CMZ");
+    assert_eq!(log.check(2022, 5, Part::One, "CMZ", now), Ok(()));
+}
+
+#[test]
+fn test_submit_remembers_wrong_answers() {
+    let stub = Stub::start();
+    let mut log = Submissions::default();
+    let now = SystemTime::now();
+    let mut submit = |answer: &str, now| {
+        submit_to(
+            stub.base_url(),
+            2022,
+            1,
+            Part::One,
+            answer,
+            &session(),
+            &mut log,
+            now,
+        )
+    };
+
+    assert_eq!(submit("30000", now).unwrap(), Feedback::TooHigh);
+
+    // Repeats and answers ruled out by the hint never reach the site.
+    assert!(matches!(
+        submit("30000", now),
+        Err(SubmitError::Refused(Refusal::Repeated(Feedback::TooHigh)))
+    ));
+    assert!(matches!(
+        submit("40000", now),
+        Err(SubmitError::Refused(Refusal::AboveTooHigh(_)))
+    ));
+
+    // Neither does anything sent during the cooldown.
+    assert!(matches!(
+        submit("20000", now),
+        Err(SubmitError::Refused(Refusal::Cooldown(_)))
+    ));
+    assert_eq!(stub.requests().len(), 1);
+
+    // Once it is over, the next guess goes through.
+    stub.clear_cooldown();
+    let later = now + Duration::from_secs(60);
+    assert_eq!(submit("20000", later).unwrap(), Feedback::TooLow);
+    assert_eq!(stub.requests().len(), 2);
+}
+
+#[test]
+fn test_submit_reads_site_cooldown() {
+    let stub = Stub::start();
+    let mut log = Submissions::default();
+    let now = SystemTime::now();
+
+    let feedback = submit_to(
+        stub.base_url(),
+        2022,
+        2,
+        Part::One,
+        "1",
+        &session(),
+        &mut log,
+        now,
+    );
+    assert_eq!(feedback.unwrap(), Feedback::TooLow);
+
+    // A log that forgot the cooldown still gets told to wait by the site.
+    let mut forgetful = Submissions::default();
+    let feedback = submit_to(
+        stub.base_url(),
+        2022,
+        2,
+        Part::One,
+        "2",
+        &session(),
+        &mut forgetful,
+        now,
+    );
+    let Ok(Feedback::Wait(wait)) = feedback else {
+        panic!("expected a wait, got {feedback:?}");
+    };
+    assert!(wait <= Duration::from_secs(60));
+    assert!(matches!(
+        forgetful.check(2022, 2, Part::One, "3", now),
+        Err(Refusal::Cooldown(_))
+    ));
+}
+
+#[test]
+fn test_submit_solved_part() {
+    let stub = Stub::start();
+    let mut log = Submissions::default();
+    let now = SystemTime::now();
+    let mut submit = |part, answer: &str| {
+        submit_to(
+            stub.base_url(),
+            2022,
+            6,
+            part,
+            answer,
+            &session(),
+            &mut log,
+            now,
+        )
+        .unwrap()
+    };
+
+    assert_eq!(submit(Part::Two, "19"), Feedback::WrongLevel);
+    assert_eq!(submit(Part::One, "7"), Feedback::Correct);
+    assert_eq!(submit(Part::One, "7"), Feedback::WrongLevel);
+    assert_eq!(submit(Part::Two, "19"), Feedback::Correct);
+}
+
+#[test]
+fn test_submit_rejected_session() {
+    let stub = Stub::start();
+    let mut log = Submissions::default();
+
+    let err = submit_to(
+        stub.base_url(),
+        2022,
+        1,
+        Part::One,
+        "24000",
+        "session=nobody",
+        &mut log,
+        SystemTime::now(),
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("answered 400"), "{err}");
+}
@@ -0,0 +1,345 @@
+//! A local stand-in for adventofcode.com, so tests can exercise the network
+//! paths without the internet or anyone's account.
+//!
+//! Point [`aoc_common`](../aoc_common/index.html) at [`Stub::base_url`]
+//! through `AOC_BASE_URL` (or `fetch_input_from`) and it serves
+//! `/YEAR/day/N/input` from fixtures, answering like the real site when the
+//! session cookie is missing or wrong. Answers posted to `/YEAR/day/N/answer`
+//! are judged against [`ANSWERS`] with the site's wording and cooldowns.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Example inputs scraped from the puzzle descriptions, by year and day.
+pub const FIXTURES: [(u16, u8, &str); 6] = [
+    (2022, 1, include_str!("../../examples/2022/day1.txt")),
+    (2022, 2, include_str!("../../examples/2022/day2.txt")),
+    (2022, 3, include_str!("../../examples/2022/day3.txt")),
+    (2022, 4, include_str!("../../examples/2022/day4.txt")),
+    (2022, 5, include_str!("../../examples/2022/day5.txt")),
+    (2022, 6, include_str!("../../examples/2022/day6.txt")),
+];
+
+/// Answers to [`FIXTURES`] as `(year, day, part, answer)`.
+pub const ANSWERS: [(u16, u8, u8, &str); 12] = [
+    (2022, 1, 1, "24000"),
+    (2022, 1, 2, "45000"),
+    (2022, 2, 1, "15"),
+    (2022, 2, 2, "12"),
+    (2022, 3, 1, "157"),
+    (2022, 3, 2, "70"),
+    (2022, 4, 1, "2"),
+    (2022, 4, 2, "4"),
+    (2022, 5, 1, "CMZ"),
+    (2022, 5, 2, "MCD"),
+    (2022, 6, 1, "7"),
+    (2022, 6, 2, "19"),
+];
+
+/// Session token the stub accepts unless told otherwise.
+pub const SESSION: &str = "stub-session";
+
+const NOT_LOGGED_IN: &str =
+    "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+const NOT_UNLOCKED: &str = "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.";
+
+const RIGHT: &str = "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.";
+const WRONG: &str = "That's not the right answer";
+const STUCK: &str = "If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2022/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.";
+const WRONG_LEVEL: &str =
+    "You don't seem to be solving the right level.  Did you already complete it?";
+
+/// Cooldown after a wrong answer, as on the real site.
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// A request the stub has seen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub method: String,
+    /// Request path, e.g. `/2022/day/4/input`.
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+struct State {
+    session: String,
+    inputs: Vec<(u16, u8, String)>,
+    answers: Vec<(u16, u8, u8, String)>,
+    solved: BTreeSet<(u16, u8, u8)>,
+    cooldown: Duration,
+    blocked_until: Option<Instant>,
+    requests: Vec<Recorded>,
+}
+
+/// A running stub server, shut down on drop.
+pub struct Stub {
+    server: Arc<Server>,
+    base_url: String,
+    state: Arc<Mutex<State>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Stub {
+    /// Serves [`FIXTURES`] to requests carrying `session=<SESSION>`.
+    pub fn start() -> Self {
+        Self::with_inputs(
+            SESSION,
+            FIXTURES
+                .iter()
+                .map(|(year, day, text)| (*year, *day, text.to_string())),
+        )
+    }
+
+    /// Serves the given `(year, day, input)`s to requests carrying
+    /// `session=<session>`.
+    pub fn with_inputs(session: &str, inputs: impl IntoIterator<Item = (u16, u8, String)>) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("bind stub server"));
+        let port = server.server_addr().to_ip().expect("ip listener").port();
+
+        let state = Arc::new(Mutex::new(State {
+            session: session.to_string(),
+            inputs: inputs.into_iter().collect(),
+            answers: ANSWERS
+                .iter()
+                .map(|(year, day, part, answer)| (*year, *day, *part, answer.to_string()))
+                .collect(),
+            solved: BTreeSet::new(),
+            cooldown: COOLDOWN,
+            blocked_until: None,
+            requests: vec![],
+        }));
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(request, &state);
+                }
+            })
+        };
+
+        Stub {
+            server,
+            base_url: format!("http://127.0.0.1:{port}"),
+            state,
+            handle: Some(handle),
+        }
+    }
+
+    /// Url to use in place of `https://adventofcode.com`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Sets the wait imposed after each wrong answer.
+    pub fn set_cooldown(&self, cooldown: Duration) {
+        self.state.lock().unwrap().cooldown = cooldown;
+    }
+
+    /// Lifts any cooldown from an earlier wrong answer.
+    pub fn clear_cooldown(&self) {
+        self.state.lock().unwrap().blocked_until = None;
+    }
+
+    /// Every request seen so far, oldest first.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for Stub {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn handle(mut request: Request, state: &Mutex<State>) {
+    let path = request.url().to_string();
+    let cookie = request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv("Cookie"))
+        .map(|h| h.value.to_string());
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+
+    let mut state = state.lock().unwrap();
+    state.requests.push(Recorded {
+        method: request.method().to_string(),
+        path: path.clone(),
+        cookie: cookie.clone(),
+        body: body.clone(),
+    });
+
+    let logged_in = cookie.is_some_and(|cookie| {
+        cookie
+            .split(';')
+            .any(|pair| pair.trim() == format!("session={}", state.session))
+    });
+
+    let (status, content_type, body) = match request.method() {
+        Method::Get if input_day(&path).is_some() && !logged_in => {
+            (400, "text/plain", NOT_LOGGED_IN.to_string())
+        }
+        Method::Get => match input_day(&path).and_then(|puzzle| {
+            state
+                .inputs
+                .iter()
+                .find(|(year, day, _)| (*year, *day) == puzzle)
+        }) {
+            Some((_, _, text)) => (200, "text/plain", text.clone()),
+            None => (404, "text/plain", NOT_UNLOCKED.to_string()),
+        },
+        Method::Post if answer_day(&path).is_some() && !logged_in => {
+            (400, "text/plain", NOT_LOGGED_IN.to_string())
+        }
+        Method::Post => match answer_day(&path) {
+            Some(puzzle) => (200, "text/html", page(&judge(&mut state, puzzle, &body))),
+            None => (404, "text/plain", "404 Not Found".to_string()),
+        },
+        _ => (404, "text/plain", "404 Not Found".to_string()),
+    };
+    drop(state);
+
+    let content_type = Header::from_bytes("Content-Type", content_type).unwrap();
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type);
+    let _ = request.respond(response);
+}
+
+/// Judges a posted `level=N&answer=X` form, returning the article text.
+fn judge(state: &mut State, (year, day): (u16, u8), form: &str) -> String {
+    if let Some(until) = state.blocked_until {
+        let now = Instant::now();
+        if until > now {
+            let left = (until - now).as_secs().max(1);
+            return format!(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}m {}s left to wait.",
+                left / 60,
+                left % 60
+            );
+        }
+    }
+
+    let field = |name: &str| {
+        form.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| decode(value))
+    };
+    let part: Option<u8> = field("level").and_then(|level| level.parse().ok());
+    let answer = field("answer").unwrap_or_default();
+    let expected = part.and_then(|part| {
+        state
+            .answers
+            .iter()
+            .find(|(y, d, p, _)| (*y, *d, *p) == (year, day, part))
+            .map(|(_, _, _, answer)| answer.clone())
+    });
+
+    let (Some(part), Some(expected)) = (part, expected) else {
+        return WRONG_LEVEL.to_string();
+    };
+    if state.solved.contains(&(year, day, part))
+        || (part == 2 && !state.solved.contains(&(year, day, 1)))
+    {
+        return WRONG_LEVEL.to_string();
+    }
+    if answer.trim() == expected {
+        state.solved.insert((year, day, part));
+        return RIGHT.to_string();
+    }
+
+    state.blocked_until = Some(Instant::now() + state.cooldown);
+    let hint = match (answer.trim().parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(given), Ok(expected)) if given > expected => "; your answer is too high",
+        (Ok(given), Ok(expected)) if given < expected => "; your answer is too low",
+        _ => "",
+    };
+    let minutes = state.cooldown.as_secs().div_ceil(60);
+    let wait = match minutes {
+        0 => String::new(),
+        1 => "  Please wait one minute before trying again.".to_string(),
+        n => format!("  Please wait {n} minutes before trying again."),
+    };
+    format!("{WRONG}{hint}.  {STUCK}{wait}")
+}
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n<article><p>{article} <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>\n</main>\n</body>\n</html>\n")
+}
+
+/// Decodes an `application/x-www-form-urlencoded` value.
+fn decode(value: &str) -> String {
+    let mut bytes = vec![];
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = iter.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => bytes.push(byte),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Year and day of a `/YEAR/day/N/input` path.
+fn input_day(path: &str) -> Option<(u16, u8)> {
+    puzzle(path.strip_suffix("/input")?)
+}
+
+/// Year and day of a `/YEAR/day/N/answer` path.
+fn answer_day(path: &str) -> Option<(u16, u8)> {
+    puzzle(path.strip_suffix("/answer")?)
+}
+
+/// Year and day of a `/YEAR/day/N` path.
+fn puzzle(path: &str) -> Option<(u16, u8)> {
+    let (year, day) = path.strip_prefix('/')?.split_once("/day/")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_day() {
+        assert_eq!(input_day("/2022/day/4/input"), Some((2022, 4)));
+        assert_eq!(input_day("/2022/day/25/input"), Some((2022, 25)));
+        assert_eq!(input_day("/2021/day/4/input"), Some((2021, 4)));
+        assert_eq!(input_day("/2022/day/4"), None);
+        assert_eq!(input_day("/2022/days/4/input"), None);
+        assert_eq!(answer_day("/2022/day/4/answer"), Some((2022, 4)));
+        assert_eq!(answer_day("/2022/day/4/input"), None);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("CMZ"), "CMZ");
+        assert_eq!(decode("a+b%2Cc"), "a b,c");
+        assert_eq!(decode("100%"), "100%");
+    }
+}
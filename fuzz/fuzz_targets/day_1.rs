@@ -0,0 +1,10 @@
+//! Calorie lists through the parser and both parts, in both widths.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = solve::<day_1::DayOne>(input, &Part::BOTH);
+    let _ = solve::<day_1::DayOneWide>(input, &Part::BOTH);
+});
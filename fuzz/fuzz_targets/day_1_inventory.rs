@@ -0,0 +1,18 @@
+//! Every inventory query on any items, however large.
+#![no_main]
+
+use day_1::Inventory;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Vec<Vec<u32>>, f64, u32, u8)| {
+    let (items, p, threshold, carriers) = input;
+    let Ok(inventory) = Inventory::from_items(items) else {
+        return;
+    };
+    let _ = day_1::part_one(&inventory);
+    let _ = day_1::part_two(&inventory);
+    let _ = inventory.percentile(p);
+    let _ = inventory.above(threshold);
+    let _ = inventory.heaviest_item();
+    let _ = inventory.rebalance(carriers.into());
+});
@@ -0,0 +1,13 @@
+//! Strategy guides through both variants, and the column parsers alone.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use day_2::{Hand, Outcome};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = input.parse::<Hand>();
+    let _ = input.parse::<Outcome>();
+    let _ = solve::<day_2::DayTwo>(input, &Part::BOTH);
+    let _ = solve::<day_2::DayTwoRayon>(input, &Part::BOTH);
+});
@@ -0,0 +1,9 @@
+//! Rucksack lists through both parts.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = solve::<day_3::DayThree>(input, &Part::BOTH);
+});
@@ -0,0 +1,10 @@
+//! Assignment lists through both variants.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = solve::<day_4::DayFour>(input, &Part::BOTH);
+    let _ = solve::<day_4::DayFourNom>(input, &Part::BOTH);
+});
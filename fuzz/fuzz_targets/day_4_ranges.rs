@@ -0,0 +1,14 @@
+//! The nom variant's parts on any pairs of ranges, reversed ones included.
+#![no_main]
+
+use day_4::with_nom::{part_one, part_two};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|pairs: Vec<((u16, u16), (u16, u16))>| {
+    let pairs: Vec<_> = pairs
+        .into_iter()
+        .map(|((a, b), (c, d))| (a..=b, c..=d))
+        .collect();
+    let _ = part_one(&pairs);
+    let _ = part_two(&pairs);
+});
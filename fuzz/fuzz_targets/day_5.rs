@@ -0,0 +1,9 @@
+//! Crate diagrams and moves through the parser and both cranes.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = solve::<day_5::DayFive>(input, &Part::BOTH);
+});
@@ -0,0 +1,23 @@
+//! Both cranes on any tower and moves, legal or not.
+#![no_main]
+
+use day_5::{part_one, part_two, Move, Tower};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Vec<Vec<bool>>, Vec<(u8, u8, u8)>)| {
+    let (stacks, moves) = input;
+    let tower: Tower<'_> = stacks
+        .iter()
+        .map(|stack| stack.iter().map(|&c| if c { "A" } else { "B" }).collect())
+        .collect();
+    let moves: Vec<Move> = moves
+        .into_iter()
+        .map(|(count, from, to)| Move {
+            count: count.into(),
+            from: from.into(),
+            to: to.into(),
+        })
+        .collect();
+    let _ = part_one(&mut tower.clone(), &moves);
+    let _ = part_two(&mut tower.clone(), &moves);
+});
@@ -0,0 +1,14 @@
+//! Signal streams through the parser, both parts and markers of any length,
+//! checked or not.
+#![no_main]
+
+use aoc_common::{solve, Part};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (&str, u8)| {
+    let (stream, len) = input;
+    let _ = solve::<day_6::DaySix>(stream, &Part::BOTH);
+    let _ = day_6::part_two(stream);
+    let _ = day_6::find_marker(stream, len.into());
+    assert_eq!(day_6::part_one(stream), day_6::find_marker(stream, 4));
+});
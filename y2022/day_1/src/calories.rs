@@ -0,0 +1,74 @@
+//! Integer widths that calorie counts and totals can be kept in.
+
+use std::fmt::{Debug, Display};
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use aoc_common::{Error, Result};
+
+/// An unsigned integer wide enough for one elf's items, added up with checks
+/// so that a total too large for it is an error rather than a wrapped sum.
+pub trait Calories:
+    Copy + Ord + Default + Debug + Display + FromStr<Err = ParseIntError> + Into<u128> + 'static
+{
+    /// Name of the type, for error messages.
+    const WIDTH: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Parses one item, telling a count too large for the width, an
+    /// [`Error::Overflow`], from one that is not a count at all.
+    fn parse_item(item: &str) -> Result<Self> {
+        item.parse().map_err(|err: ParseIntError| match err.kind() {
+            IntErrorKind::PosOverflow => {
+                Error::overflow(format!("calorie count '{item}' overflows {}", Self::WIDTH))
+            }
+            _ => Error::invalid(format!("invalid calorie count '{item}'")),
+        })
+    }
+}
+
+macro_rules! calories {
+    ($($width:ty),*) => {
+        $(
+            impl Calories for $width {
+                const WIDTH: &'static str = stringify!($width);
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$width>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+calories!(u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day1_parse_item_at_the_edges() {
+        assert_eq!(u32::parse_item("4294967295"), Ok(u32::MAX));
+        assert_eq!(
+            u32::parse_item("4294967296"),
+            Err(Error::overflow("calorie count '4294967296' overflows u32"))
+        );
+        assert_eq!(u64::parse_item("4294967296"), Ok(1 << 32));
+        assert_eq!(u128::parse_item(&u128::MAX.to_string()), Ok(u128::MAX));
+        assert_eq!(
+            u128::parse_item("340282366920938463463374607431768211456"),
+            Err(Error::overflow(
+                "calorie count '340282366920938463463374607431768211456' overflows u128"
+            ))
+        );
+
+        for item in ["-1", "1e3", " 1", ""] {
+            assert_eq!(
+                u64::parse_item(item),
+                Err(Error::invalid(format!("invalid calorie count '{item}'")))
+            );
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! Every item of every elf, kept for questions beyond the two parts.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use aoc_common::{Error, Result};
+
+use crate::calories::Calories;
+use crate::stream::{largest, ElfTotal, Elves};
+
+/// The items one elf carries, numbered from zero in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf<T = u32> {
+    pub index: usize,
+    pub items: Vec<T>,
+    pub total: T,
+}
+
+impl<T: Calories> Elf<T> {
+    /// Builds the `index`th elf, failing if its total does not fit in `T`.
+    pub fn new(index: usize, items: Vec<T>) -> Result<Self> {
+        let total = items
+            .iter()
+            .try_fold(T::default(), |sum, &calories| sum.checked_add(calories))
+            .ok_or_else(|| {
+                Error::overflow(format!(
+                    "elf {index}'s calorie total overflows {}",
+                    T::WIDTH
+                ))
+            })?;
+        Ok(Elf {
+            index,
+            items,
+            total,
+        })
+    }
+
+    pub fn heaviest_item(&self) -> Option<T> {
+        self.items.iter().max().copied()
+    }
+}
+
+/// An item and the elf who brought it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item<T = u32> {
+    pub elf: usize,
+    pub calories: T,
+}
+
+/// What one carrier takes on when the items are shared out again. Loads are
+/// kept in the widest type, since one carrier may take several elves' worth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Carrier<T = u32> {
+    pub items: Vec<Item<T>>,
+    pub load: u128,
+}
+
+impl<T> Default for Carrier<T> {
+    fn default() -> Self {
+        Carrier {
+            items: vec![],
+            load: 0,
+        }
+    }
+}
+
+/// All the elves of a calorie list, in input order, with counts and totals
+/// kept as `T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory<T = u32> {
+    elves: Vec<Elf<T>>,
+}
+
+impl<T> Default for Inventory<T> {
+    fn default() -> Self {
+        Inventory { elves: vec![] }
+    }
+}
+
+impl<T: Calories> Inventory<T> {
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::read(input.as_bytes())
+    }
+
+    pub fn read(reader: impl BufRead) -> Result<Self> {
+        let elves = Elves::new(reader).collect::<Result<_>>()?;
+        Ok(Inventory { elves })
+    }
+
+    /// Inventory of elves carrying `items`, in order.
+    pub fn from_items(items: impl IntoIterator<Item = Vec<T>>) -> Result<Self> {
+        let elves = (0..)
+            .zip(items)
+            .map(|(index, items)| Elf::new(index, items))
+            .collect::<Result<_>>()?;
+        Ok(Inventory { elves })
+    }
+
+    pub fn elves(&self) -> &[Elf<T>] {
+        &self.elves
+    }
+
+    pub fn totals(&self) -> Vec<T> {
+        self.elves.iter().map(|elf| elf.total).collect()
+    }
+
+    /// The `k` best-stocked elves, best first, ties going to the earlier elf.
+    pub fn top(&self, k: usize) -> Vec<&Elf<T>> {
+        let totals = self.elves.iter().map(|elf| ElfTotal {
+            elf: elf.index,
+            total: elf.total,
+        });
+        largest(totals, k)
+            .into_iter()
+            .map(|top| &self.elves[top.elf])
+            .collect()
+    }
+
+    /// Calories carried by the `k` best-stocked elves together.
+    pub fn top_total(&self, k: usize) -> Result<T> {
+        self.top(k)
+            .iter()
+            .try_fold(T::default(), |sum, elf| sum.checked_add(elf.total))
+            .ok_or_else(|| {
+                Error::overflow(format!("the top {k} calorie totals overflow {}", T::WIDTH))
+            })
+    }
+
+    /// Load that `p` percent of elves carry at most, by the nearest-rank
+    /// method; `None` without elves or outside `0.0..=100.0`.
+    pub fn percentile(&self, p: f64) -> Option<T> {
+        if self.elves.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let mut totals = self.totals();
+        totals.sort_unstable();
+        let rank = (p / 100.0 * totals.len() as f64).ceil() as usize;
+        Some(totals[rank.max(1) - 1])
+    }
+
+    /// Middle load, the lower of the two middle ones for an even count.
+    pub fn median(&self) -> Option<T> {
+        self.percentile(50.0)
+    }
+
+    /// Elves carrying more than `threshold` calories, in input order.
+    pub fn above(&self, threshold: T) -> Vec<&Elf<T>> {
+        self.elves
+            .iter()
+            .filter(|elf| elf.total > threshold)
+            .collect()
+    }
+
+    /// The elf with the heaviest single item and that item, the earlier elf on
+    /// ties.
+    pub fn heaviest_item(&self) -> Option<(&Elf<T>, T)> {
+        self.elves
+            .iter()
+            .filter_map(|elf| Some((elf, elf.heaviest_item()?)))
+            .min_by_key(|&(elf, item)| (Reverse(item), elf.index))
+    }
+
+    /// Shares every item out between `carriers`, keeping the heaviest load
+    /// low: items go heaviest first to whoever carries least so far, which is
+    /// never more than a third above the best possible split. Only `u128`
+    /// items can make a load overflow.
+    pub fn rebalance(&self, carriers: usize) -> Result<Vec<Carrier<T>>> {
+        if carriers == 0 {
+            return Ok(vec![]);
+        }
+        let mut items: Vec<Item<T>> = self
+            .elves
+            .iter()
+            .flat_map(|elf| {
+                elf.items.iter().map(|&calories| Item {
+                    elf: elf.index,
+                    calories,
+                })
+            })
+            .collect();
+        items.sort_by_key(|item| (Reverse(item.calories), item.elf));
+
+        let mut shared = vec![Carrier::default(); carriers];
+        let mut lightest: BinaryHeap<Reverse<(u128, usize)>> =
+            (0..carriers).map(|carrier| Reverse((0, carrier))).collect();
+        for item in items {
+            let Reverse((load, carrier)) = lightest.pop().unwrap();
+            let load = load.checked_add(item.calories.into()).ok_or_else(|| {
+                Error::overflow(format!("carrier {carrier}'s load overflows u128"))
+            })?;
+            shared[carrier].items.push(item);
+            shared[carrier].load = load;
+            lightest.push(Reverse((load, carrier)));
+        }
+        Ok(shared)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str =
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    fn indices<T>(elves: &[&Elf<T>]) -> Vec<usize> {
+        elves.iter().map(|elf| elf.index).collect()
+    }
+
+    #[test]
+    fn test_day1_inventory_sample() {
+        let inventory: Inventory = Inventory::parse(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(inventory.elves()[2].items, [5000, 6000]);
+        assert_eq!(inventory.totals(), [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(indices(&inventory.top(3)), [3, 2, 4]);
+        assert_eq!(inventory.top_total(1).unwrap(), 24000);
+        assert_eq!(inventory.top_total(3).unwrap(), 45000);
+    }
+
+    #[test]
+    fn test_day1_inventory_percentiles() {
+        let inventory: Inventory = Inventory::parse(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(inventory.median(), Some(10000));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(20.0), Some(4000));
+        assert_eq!(inventory.percentile(21.0), Some(6000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(101.0), None);
+        assert_eq!(Inventory::<u32>::default().median(), None);
+    }
+
+    #[test]
+    fn test_day1_inventory_queries() {
+        let inventory: Inventory = Inventory::parse(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(indices(&inventory.above(10000)), [2, 3]);
+        assert!(inventory.above(24000).is_empty());
+
+        let (elf, item) = inventory.heaviest_item().unwrap();
+        assert_eq!((elf.index, item), (4, 10000));
+        let tied = Inventory::<u32>::from_items([vec![1, 9], vec![9]]).unwrap();
+        assert_eq!(tied.heaviest_item().unwrap().0.index, 0);
+        assert_eq!(Inventory::<u32>::default().heaviest_item(), None);
+    }
+
+    #[test]
+    fn test_day1_inventory_rebalance() {
+        let inventory: Inventory = Inventory::parse(SAMPLE_INPUT).unwrap();
+
+        let carriers = inventory.rebalance(3).unwrap();
+        let loads: Vec<u128> = carriers.iter().map(|carrier| carrier.load).collect();
+        assert_eq!(loads, [19000, 18000, 18000]);
+        assert_eq!(
+            carriers[0].items,
+            [
+                Item {
+                    elf: 4,
+                    calories: 10000
+                },
+                Item {
+                    elf: 2,
+                    calories: 5000
+                },
+                Item {
+                    elf: 1,
+                    calories: 4000
+                }
+            ]
+        );
+        let items: usize = carriers.iter().map(|carrier| carrier.items.len()).sum();
+        assert_eq!(items, 10);
+
+        assert!(inventory.rebalance(0).unwrap().is_empty());
+        assert_eq!(inventory.rebalance(1).unwrap()[0].load, 55000);
+    }
+
+    #[test]
+    fn test_day1_inventory_overflow() {
+        let err = Inventory::<u32>::from_items([vec![1], vec![u32::MAX, 1]]).unwrap_err();
+        assert_eq!(err.to_string(), "elf 1's calorie total overflows u32");
+
+        let inventory = Inventory::<u32>::from_items([vec![u32::MAX], vec![1]]).unwrap();
+        let err = inventory.top_total(2).unwrap_err();
+        assert_eq!(
+            err,
+            Error::overflow("the top 2 calorie totals overflow u32")
+        );
+        assert_eq!(inventory.rebalance(1).unwrap()[0].load, 1 << 32);
+    }
+
+    /// Totals that sit exactly on the limit of each width, then one past it.
+    #[test]
+    fn test_day1_inventory_overflow_at_every_width() {
+        fn at_the_limit<T: Calories>(max: T, one: T) {
+            let inventory = Inventory::from_items([vec![max], vec![one]]).unwrap();
+            assert_eq!(inventory.top_total(1).unwrap(), max);
+            assert_eq!(
+                inventory.top_total(2).unwrap_err().to_string(),
+                format!("the top 2 calorie totals overflow {}", T::WIDTH)
+            );
+
+            let err = Inventory::from_items([vec![one], vec![one], vec![max, one]]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("elf 2's calorie total overflows {}", T::WIDTH)
+            );
+        }
+
+        at_the_limit(u32::MAX, 1);
+        at_the_limit(u64::MAX, 1);
+        at_the_limit(u128::MAX, 1);
+    }
+
+    /// A u32 list whose every total and top-three sum overflows u32 is still
+    /// answered in u64, and u128 carriers report the load that overflows.
+    #[test]
+    fn test_day1_inventory_wide_totals() {
+        let items = vec![u32::MAX; 1000];
+        let input = vec![
+            items
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            3
+        ]
+        .join("\n\n");
+        let err = Inventory::<u32>::parse(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "elf 0's calorie total overflows u32 on line 2"
+        );
+
+        let inventory = Inventory::<u64>::parse(&input).unwrap();
+        assert_eq!(inventory.totals(), vec![1000 * u64::from(u32::MAX); 3]);
+        assert_eq!(inventory.top_total(3).unwrap(), 3000 * u64::from(u32::MAX));
+        assert_eq!(
+            inventory.rebalance(1).unwrap()[0].load,
+            3000 * u128::from(u32::MAX)
+        );
+
+        let inventory = Inventory::<u128>::from_items([vec![u128::MAX], vec![1]]).unwrap();
+        assert_eq!(
+            inventory.rebalance(1).unwrap_err().to_string(),
+            "carrier 0's load overflows u128"
+        );
+        assert_eq!(inventory.rebalance(2).unwrap()[0].load, u128::MAX);
+    }
+}
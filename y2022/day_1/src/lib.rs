@@ -0,0 +1,178 @@
+//! Day 1: Calorie Counting.
+//!
+//! The input lists the calories of each item carried by the elves, one item
+//! per line, with a blank line between elves. [`DayOne`] counts in `u32`, as
+//! the puzzle needs; [`DayOneWide`] counts in `u128` for lists whose totals
+//! would overflow it.
+
+pub mod calories;
+pub mod inventory;
+pub mod stream;
+
+use aoc_common::{Error, Result, Solution};
+
+pub use crate::calories::Calories;
+pub use crate::inventory::{Elf, Inventory};
+
+/// Every elf and the items each carries, in input order.
+pub fn parse(input: &str) -> Result<Inventory> {
+    Inventory::parse(input)
+}
+
+/// Calories carried together by the `k` best-stocked elves, or all of them if
+/// there are fewer, as long as there is one.
+fn best_stocked<T: Calories>(inventory: &Inventory<T>, k: usize) -> Result<T> {
+    if inventory.elves().is_empty() {
+        return Err(Error::no_answer("no elves in input"));
+    }
+    inventory.top_total(k)
+}
+
+/// Calories carried by the best-stocked elf.
+pub fn part_one<T: Calories>(inventory: &Inventory<T>) -> Result<T> {
+    best_stocked(inventory, 1)
+}
+
+/// Calories carried by the three best-stocked elves together.
+pub fn part_two<T: Calories>(inventory: &Inventory<T>) -> Result<T> {
+    best_stocked(inventory, 3)
+}
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const NAME: &'static str = "Day One";
+
+    type Parsed<'a> = Inventory;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse(input)
+    }
+
+    fn part_one(inventory: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(inventory)
+    }
+
+    fn part_two(inventory: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(inventory)
+    }
+}
+
+pub struct DayOneWide;
+
+impl Solution for DayOneWide {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const NAME: &'static str = "Day One (Wide)";
+    const VARIANT: &'static str = "wide";
+
+    type Parsed<'a> = Inventory<u128>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Inventory::parse(input)
+    }
+
+    fn part_one(inventory: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(inventory)
+    }
+
+    fn part_two(inventory: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(inventory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str =
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    #[test]
+    fn test_day1_part1_sample() {
+        let inventory = parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(inventory.totals(), vec![6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(part_one(&inventory).unwrap(), 24000);
+    }
+
+    #[test]
+    fn test_day1_part2_sample() {
+        assert_eq!(part_two(&parse(SAMPLE_INPUT).unwrap()).unwrap(), 45000);
+    }
+
+    #[test]
+    fn test_day1_trailing_newline() {
+        let inventory = parse(&format!("{SAMPLE_INPUT}\n")).unwrap();
+        assert_eq!(inventory.totals(), vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn test_day1_no_elves() {
+        for input in ["", "\n\n"] {
+            let inventory = parse(input).unwrap();
+            assert_eq!(
+                part_one(&inventory),
+                Err(Error::no_answer("no elves in input"))
+            );
+            assert_eq!(
+                part_two(&inventory),
+                Err(Error::no_answer("no elves in input"))
+            );
+        }
+
+        // Fewer than three elves still share out what they carry.
+        assert_eq!(part_two(&parse("7\n\n8").unwrap()).unwrap(), 15);
+    }
+
+    #[test]
+    fn test_day1_parse_error() {
+        let err = parse("1000\n2000\n\n3o00").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: invalid calorie count '3o00'"
+        );
+    }
+
+    /// Found by the `day_1` and `day_1_inventory` fuzz targets, which used to
+    /// panic on the overflowing sums.
+    #[test]
+    fn test_day1_overflow() {
+        let err = parse("4000000000\n300000000").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "elf 0's calorie total overflows u32 on line 2"
+        );
+
+        let inventory = parse("2222222227\n\n2222222226\n\n7").unwrap();
+        assert_eq!(part_one(&inventory).unwrap(), 2222222227);
+        let err = part_two(&inventory).unwrap_err();
+        assert_eq!(err.to_string(), "the top 3 calorie totals overflow u32");
+        assert!(matches!(err, Error::Overflow(_)));
+    }
+
+    #[test]
+    fn test_day1_wide_answers_past_u32() {
+        let input = "4000000000\n300000000\n\n2222222227\n\n2222222226\n\n7";
+        let inventory = DayOneWide::parse(input).unwrap();
+        assert_eq!(DayOneWide::part_one(&inventory).unwrap(), 4300000000);
+        assert_eq!(DayOneWide::part_two(&inventory).unwrap(), 8744444453);
+
+        let max = u128::MAX.to_string();
+        let err = DayOneWide::parse(&format!("7\n\n{max}\n0\n1")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "elf 1's calorie total overflows u128 on line 5"
+        );
+        let err = DayOneWide::parse(&format!("{max}0")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("calorie count '{max}0' overflows u128 on line 1")
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_1::DayOne;
+
+fn main() {
+    run::<DayOne>();
+}
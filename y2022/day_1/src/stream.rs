@@ -0,0 +1,367 @@
+//! Reads calorie lists one line at a time, so inputs far larger than memory
+//! can be ranked.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+use std::marker::PhantomData;
+
+use aoc_common::{Error, Result};
+
+use crate::calories::Calories;
+use crate::inventory::Elf;
+
+/// Calories carried by one elf, numbered from zero in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal<T = u32> {
+    pub elf: usize,
+    pub total: T,
+}
+
+/// The lines of a calorie list, read one at a time.
+struct Lines<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn new(reader: R) -> Self {
+        Lines {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// Next line without its line ending, `None` at the end of the input.
+    fn next_line(&mut self) -> Result<Option<&str>> {
+        self.line.clear();
+        let read = self
+            .reader
+            .read_line(&mut self.line)
+            .map_err(|err| Error::invalid(format!("reading the input: {err}")))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        self.line_number += 1;
+        Ok(Some(self.line.trim_end_matches(['\n', '\r'])))
+    }
+
+    /// Pins `err` to the current line: bad input becomes a parse error there,
+    /// and an overflow names the line it happened on.
+    fn locate(&self, err: Error) -> Error {
+        match err {
+            Error::Invalid(message) => Error::Parse {
+                line: self.line_number,
+                column: 1,
+                message,
+            },
+            Error::Overflow(message) => {
+                Error::overflow(format!("{message} on line {}", self.line_number))
+            }
+            other => other,
+        }
+    }
+
+    /// Reads the items of the `elf`th elf, handing each to `item`, and returns
+    /// their total; `None` once no elf is left.
+    ///
+    /// Any run of blank lines separates two elves, so leading, trailing and
+    /// doubled blank lines are all accepted.
+    fn next_elf<T: Calories>(&mut self, elf: usize, mut item: impl FnMut(T)) -> Result<Option<T>> {
+        let mut total: Option<T> = None;
+        while let Some(line) = self.next_line()? {
+            if line.is_empty() {
+                if total.is_some() {
+                    break;
+                }
+                continue;
+            }
+            let sum = T::parse_item(line).and_then(|calories| {
+                item(calories);
+                total
+                    .unwrap_or_default()
+                    .checked_add(calories)
+                    .ok_or_else(|| {
+                        Error::overflow(format!("elf {elf}'s calorie total overflows {}", T::WIDTH))
+                    })
+            });
+            total = Some(sum.map_err(|err| self.locate(err))?);
+        }
+        Ok(total)
+    }
+}
+
+/// Iterator over the elves of a calorie list, holding one elf's items at a
+/// time. Counts and totals are kept as `T`.
+pub struct Elves<R, T = u32> {
+    lines: Lines<R>,
+    elf: usize,
+    width: PhantomData<T>,
+}
+
+impl<R: BufRead, T: Calories> Elves<R, T> {
+    pub fn new(reader: R) -> Self {
+        Elves {
+            lines: Lines::new(reader),
+            elf: 0,
+            width: PhantomData,
+        }
+    }
+
+    fn next_elf(&mut self) -> Result<Option<Elf<T>>> {
+        let mut items = vec![];
+        let Some(total) = self
+            .lines
+            .next_elf(self.elf, |calories| items.push(calories))?
+        else {
+            return Ok(None);
+        };
+        self.elf += 1;
+        Ok(Some(Elf {
+            index: self.elf - 1,
+            items,
+            total,
+        }))
+    }
+}
+
+impl<R: BufRead, T: Calories> Iterator for Elves<R, T> {
+    type Item = Result<Elf<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_elf().transpose()
+    }
+}
+
+/// Iterator over the totals of a calorie list, holding one line at a time
+/// however many items an elf carries. Totals are kept as `T`.
+pub struct Totals<R, T = u32> {
+    lines: Lines<R>,
+    elf: usize,
+    width: PhantomData<T>,
+}
+
+impl<R: BufRead, T: Calories> Totals<R, T> {
+    pub fn new(reader: R) -> Self {
+        Totals {
+            lines: Lines::new(reader),
+            elf: 0,
+            width: PhantomData,
+        }
+    }
+
+    fn next_total(&mut self) -> Result<Option<ElfTotal<T>>> {
+        let Some(total) = self.lines.next_elf(self.elf, |_| {})? else {
+            return Ok(None);
+        };
+        self.elf += 1;
+        Ok(Some(ElfTotal {
+            elf: self.elf - 1,
+            total,
+        }))
+    }
+}
+
+impl<R: BufRead, T: Calories> Iterator for Totals<R, T> {
+    type Item = Result<ElfTotal<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_total().transpose()
+    }
+}
+
+/// The `k` largest totals, largest first, ties going to the earlier elf.
+///
+/// Only `k` totals are held at any time, in a min-heap whose smallest entry is
+/// the one to drop.
+pub fn largest<T: Ord>(
+    totals: impl IntoIterator<Item = ElfTotal<T>>,
+    k: usize,
+) -> Vec<ElfTotal<T>> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for ElfTotal { elf, total } in totals {
+        heap.push(Reverse((total, Reverse(elf))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(elf)))| ElfTotal { elf, total })
+        .collect()
+}
+
+/// The `k` best-stocked elves of a calorie list read from `reader`, largest
+/// total first, in memory bounded by `k` and the longest line.
+pub fn top_k<T: Calories>(reader: impl BufRead, k: usize) -> Result<Vec<ElfTotal<T>>> {
+    let mut error = None;
+    let totals = Totals::<_, T>::new(reader).map_while(|total| match total {
+        Ok(total) => Some(total),
+        Err(err) => {
+            error = Some(err);
+            None
+        }
+    });
+    let top = largest(totals, k);
+    match error {
+        Some(err) => Err(err),
+        None => Ok(top),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{self, BufReader, Read};
+
+    const SAMPLE_INPUT: &str =
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    fn elf(elf: usize, total: u32) -> ElfTotal {
+        ElfTotal { elf, total }
+    }
+
+    #[test]
+    fn test_day1_top_k_sample() {
+        let top = top_k(SAMPLE_INPUT.as_bytes(), 3).unwrap();
+        assert_eq!(top, [elf(3, 24000), elf(2, 11000), elf(4, 10000)]);
+
+        assert_eq!(top_k::<u32>(SAMPLE_INPUT.as_bytes(), 0).unwrap(), []);
+        assert_eq!(top_k::<u32>(SAMPLE_INPUT.as_bytes(), 9).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_day1_top_k_blank_lines() {
+        let input = format!("\n{}\r\n\n\n", SAMPLE_INPUT.replace("\n\n", "\n\n\n"));
+        let elves: Vec<Elf> = Elves::new(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(elves.len(), 5);
+        assert_eq!(elves[4].items, [10000]);
+        assert_eq!(elves[4].index, 4);
+
+        assert_eq!(top_k::<u32>("".as_bytes(), 3).unwrap(), []);
+    }
+
+    #[test]
+    fn test_day1_top_k_ties_go_to_the_earlier_elf() {
+        let top = top_k("5\n\n7\n\n5\n\n7\n\n5".as_bytes(), 3).unwrap();
+        assert_eq!(top, [elf(1, 7), elf(3, 7), elf(0, 5)]);
+    }
+
+    #[test]
+    fn test_day1_top_k_errors() {
+        let err = top_k::<u32>("1000\n\n10x0\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: invalid calorie count '10x0'"
+        );
+    }
+
+    #[test]
+    fn test_day1_top_k_names_the_overflowing_elf() {
+        let input = "1\n\n\n4294967295\n0\n1\n2\n\n3";
+        let err = top_k::<u32>(input.as_bytes(), 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "elf 1's calorie total overflows u32 on line 6"
+        );
+        assert!(matches!(err, Error::Overflow(_)));
+
+        let top = top_k::<u64>(input.as_bytes(), 1).unwrap();
+        assert_eq!(
+            top,
+            [ElfTotal {
+                elf: 1,
+                total: (1 << 32) + 2
+            }]
+        );
+    }
+
+    /// Endless-looking calorie list, written as it is read: elf `n` carries
+    /// `n % 1000` and `n / 1000` calories.
+    struct Generated {
+        elves: u32,
+        next: u32,
+        pending: Vec<u8>,
+    }
+
+    impl Read for Generated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.pending.is_empty() && self.next < self.elves {
+                let n = self.next;
+                self.pending = format!("{}\n{}\n\n", n % 1000, n / 1000).into_bytes();
+                self.next += 1;
+            }
+            let len = buf.len().min(self.pending.len());
+            buf[..len].copy_from_slice(&self.pending[..len]);
+            self.pending.drain(..len);
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn test_day1_top_k_streams_large_inputs() {
+        let elves = 300_000;
+        let reader = BufReader::new(Generated {
+            elves,
+            next: 0,
+            pending: vec![],
+        });
+
+        // 299_999 carries 999 + 299, then 298_999 with 999 + 298 ties 299_998.
+        let top = top_k(reader, 3).unwrap();
+        assert_eq!(
+            top,
+            [elf(299_999, 1298), elf(298_999, 1297), elf(299_998, 1297)]
+        );
+    }
+
+    /// One line over and over, `times` times.
+    struct Repeated {
+        line: &'static [u8],
+        times: usize,
+        at: usize,
+    }
+
+    impl Read for Repeated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.times == 0 {
+                return Ok(0);
+            }
+            let len = buf.len().min(self.line.len() - self.at);
+            buf[..len].copy_from_slice(&self.line[self.at..self.at + len]);
+            self.at += len;
+            if self.at == self.line.len() {
+                self.at = 0;
+                self.times -= 1;
+            }
+            Ok(len)
+        }
+    }
+
+    /// Only the running total of an elf is kept, not its items.
+    #[test]
+    fn test_day1_top_k_streams_one_huge_elf() {
+        let reader = BufReader::new(Repeated {
+            line: b"3\n",
+            times: 500_000,
+            at: 0,
+        });
+        assert_eq!(top_k(reader, 2).unwrap(), [elf(0, 1_500_000)]);
+
+        let reader = BufReader::new(Repeated {
+            line: b"4294967295\n",
+            times: 500_000,
+            at: 0,
+        });
+        let totals: Vec<ElfTotal<u64>> = Totals::new(reader).map(Result::unwrap).collect();
+        assert_eq!(
+            totals,
+            [ElfTotal {
+                elf: 0,
+                total: 500_000 * u64::from(u32::MAX)
+            }]
+        );
+    }
+}
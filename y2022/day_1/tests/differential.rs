@@ -0,0 +1,116 @@
+//! Cross-checks the `u32` and `u128` variants, and totals kept in each width,
+//! on generated calorie lists: every width gives the same answers, except
+//! that one too narrow for the totals reports that they overflow it where
+//! the wider ones answer.
+
+mod strategies;
+
+use aoc_common::{cross_check, Entry, Error};
+use day_1::{part_one, part_two, Calories, DayOne, DayOneWide, Inventory};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use strategies::{elves, huge_elves, render};
+
+fn variants() -> [Entry; 2] {
+    [Entry::of::<DayOne>(), Entry::of::<DayOneWide>()]
+}
+
+/// Both answers with totals kept in `T`, or why there are none.
+fn answers<T: Calories>(input: &str) -> (&'static str, [Result<String, Error>; 2]) {
+    let answers = match Inventory::<T>::parse(input) {
+        Ok(inventory) => [
+            part_one(&inventory).map(|answer| answer.to_string()),
+            part_two(&inventory).map(|answer| answer.to_string()),
+        ],
+        Err(err) => [Err(err.clone()), Err(err)],
+    };
+    (T::WIDTH, answers)
+}
+
+/// Checks that `u32`, `u64` and `u128` totals answer each part alike, except
+/// for the narrower widths overflowing where the wider ones succeed.
+fn widths_agree(input: &str) -> Result<(), TestCaseError> {
+    let widths = [
+        answers::<u32>(input),
+        answers::<u64>(input),
+        answers::<u128>(input),
+    ];
+    for part in 0..2 {
+        let overflows: Vec<bool> = widths
+            .iter()
+            .map(|(_, answers)| matches!(answers[part], Err(Error::Overflow(_))))
+            .collect();
+        let widest = &widths[widths.len() - 1].1[part];
+        prop_assert!(!overflows[widths.len() - 1], "{:?}", widest);
+        for (i, (width, answers)) in widths.iter().enumerate() {
+            if overflows[i] {
+                prop_assert!(widest.is_ok(), "{} overflows, but {:?}", width, widest);
+            } else {
+                prop_assert_eq!(&answers[part], widest, "{} disagrees", width);
+            }
+        }
+        // A width that overflows is narrower than every width that does not.
+        prop_assert!(
+            overflows.windows(2).all(|pair| pair[0] || !pair[1]),
+            "{:?}",
+            overflows
+        );
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn test_day1_variants_agree(elves in elves()) {
+        prop_assert_eq!(cross_check(&render(&elves), &variants()), Ok(()));
+        widths_agree(&render(&elves))?;
+    }
+
+    /// Thirty digits reach past `u32` and `u64`, but never `u128`.
+    #[test]
+    fn test_day1_variants_agree_on_noise(input in "[0-9\n]{0,30}") {
+        widths_agree(&input)?;
+    }
+
+    #[test]
+    fn test_day1_variants_differ_only_on_overflow(elves in huge_elves()) {
+        let input = render(&elves);
+        if let Err(message) = cross_check(&input, &variants()) {
+            prop_assert!(message.contains("default = error"), "{}", message);
+        }
+        widths_agree(&input)?;
+    }
+}
+
+/// Lists the properties above once shrank to, where only the narrower
+/// widths overflow.
+#[test]
+fn test_day1_widths_overflow_in_order() {
+    for input in ["5000000000", "4300000000", "2222222227\n\n2222222226\n\n7"] {
+        widths_agree(input).unwrap();
+    }
+
+    let (_, [one, _]) = answers::<u32>("5000000000");
+    assert_eq!(
+        one.unwrap_err(),
+        Error::overflow("calorie count '5000000000' overflows u32 on line 1")
+    );
+    let (_, [_, two]) = answers::<u32>("2222222227\n\n2222222226\n\n7");
+    assert_eq!(
+        two.unwrap_err(),
+        Error::overflow("the top 3 calorie totals overflow u32")
+    );
+    let (_, [one, _]) = answers::<u64>("99999999999999999999\n\n1");
+    assert_eq!(
+        one.unwrap_err(),
+        Error::overflow("calorie count '99999999999999999999' overflows u64 on line 1")
+    );
+    let (_, [one, two]) = answers::<u128>("99999999999999999999\n\n1");
+    assert_eq!(
+        (one.unwrap(), two.unwrap()),
+        (
+            "99999999999999999999".to_string(),
+            "100000000000000000000".to_string()
+        )
+    );
+}
@@ -0,0 +1,11 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day1.txt`.
+
+#[test]
+fn test_day1_example_dayone() {
+    aoc_common::assert_example::<day_1::DayOne>();
+}
+
+#[test]
+fn test_day1_example_dayonewide() {
+    aoc_common::assert_example::<day_1::DayOneWide>();
+}
@@ -0,0 +1,90 @@
+//! Invariants of the calorie counts on generated elves.
+
+mod strategies;
+
+use aoc_common::Error;
+use day_1::{parse, part_one, part_two, Inventory};
+use proptest::prelude::*;
+use strategies::{elves, huge_elves, render};
+
+proptest! {
+    #[test]
+    fn test_day1_parse_keeps_each_item(elves in elves()) {
+        let inventory = parse(&render(&elves)).unwrap();
+        let items: Vec<Vec<u32>> = inventory.elves().iter().map(|elf| elf.items.clone()).collect();
+        prop_assert_eq!(&items, &elves);
+        let expected: Vec<u32> = elves.iter().map(|items| items.iter().sum()).collect();
+        prop_assert_eq!(inventory.totals(), expected);
+    }
+
+    #[test]
+    fn test_day1_parts_pick_the_best_stocked(elves in elves()) {
+        let inventory = parse(&render(&elves)).unwrap();
+        let best = part_one(&inventory).unwrap();
+        let top_three = part_two(&inventory).unwrap();
+        let mut totals = inventory.totals();
+
+        prop_assert!(totals.iter().all(|&total| total <= best));
+        prop_assert!(best <= top_three && top_three <= best.saturating_mul(3));
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        prop_assert_eq!(top_three, totals.iter().take(3).sum::<u32>());
+    }
+
+    #[test]
+    fn test_day1_elf_order_does_not_matter(mut elves in elves()) {
+        let before = parse(&render(&elves)).unwrap();
+        elves.reverse();
+        let after = parse(&render(&elves)).unwrap();
+        prop_assert_eq!(part_one(&before).unwrap(), part_one(&after).unwrap());
+        prop_assert_eq!(part_two(&before).unwrap(), part_two(&after).unwrap());
+    }
+
+    #[test]
+    fn test_day1_percentiles_are_ordered(elves in elves(), p in 0.0..=100.0f64, q in 0.0..=100.0f64) {
+        let inventory = parse(&render(&elves)).unwrap();
+        let (low, high) = (p.min(q), p.max(q));
+        prop_assert!(inventory.percentile(low) <= inventory.percentile(high));
+        prop_assert_eq!(inventory.percentile(100.0), Some(part_one(&inventory).unwrap()));
+        let median = inventory.median().unwrap();
+        prop_assert!(inventory.above(median).len() <= elves.len() / 2);
+    }
+
+    #[test]
+    fn test_day1_rebalance_shares_out_every_item(elves in elves(), carriers in 1..10usize) {
+        let inventory = parse(&render(&elves)).unwrap();
+        let shared = inventory.rebalance(carriers).unwrap();
+        prop_assert_eq!(shared.len(), carriers);
+
+        let mut moved: Vec<u32> = shared.iter().flat_map(|c| c.items.iter().map(|item| item.calories)).collect();
+        let mut items: Vec<u32> = elves.concat();
+        moved.sort_unstable();
+        items.sort_unstable();
+        prop_assert_eq!(&moved, &items);
+
+        // Whoever ends up heaviest was the lightest before their last item.
+        let total: u128 = items.iter().map(|&item| u128::from(item)).sum();
+        let heaviest = shared.iter().map(|c| c.load).max().unwrap();
+        let largest = u128::from(inventory.heaviest_item().unwrap().1);
+        prop_assert!(heaviest <= total / carriers as u128 + largest);
+    }
+
+    #[test]
+    fn test_day1_widths_agree_until_u32_overflows(elves in huge_elves()) {
+        let input = render(&elves);
+        let wide = Inventory::<u64>::parse(&input).unwrap();
+        let expected: Vec<u64> = elves.iter().map(|items| items.iter().map(|&item| u64::from(item)).sum()).collect();
+        prop_assert_eq!(wide.totals(), expected);
+
+        match parse(&input) {
+            Ok(narrow) => {
+                let narrow: Vec<u64> = narrow.totals().into_iter().map(u64::from).collect();
+                prop_assert_eq!(narrow, wide.totals());
+            }
+            Err(err) => {
+                let elf = wide.totals().iter().position(|&total| total > u64::from(u32::MAX)).unwrap();
+                let overflow = format!("elf {elf}'s calorie total overflows u32 on line ");
+                prop_assert!(matches!(&err, Error::Overflow(message) if message.starts_with(&overflow)), "{}", err);
+            }
+        }
+    }
+}
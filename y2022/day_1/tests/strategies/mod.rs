@@ -0,0 +1,28 @@
+//! Generators of valid calorie lists.
+
+use proptest::prelude::*;
+
+/// Calories of each elf's items, small enough that no total overflows.
+pub fn elves() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    prop::collection::vec(prop::collection::vec(0..=100_000u32, 1..10), 1..30)
+}
+
+/// Calories of items close to the `u32` limit, so that some totals overflow
+/// it and none overflow `u64`.
+pub fn huge_elves() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    let item = prop_oneof![0..=100_000u32, u32::MAX - 100_000..=u32::MAX];
+    prop::collection::vec(prop::collection::vec(item, 1..10), 1..30)
+}
+
+/// The calorie list as the puzzle writes it, one item per line and a blank
+/// line between elves.
+pub fn render(elves: &[Vec<u32>]) -> String {
+    let groups: Vec<String> = elves
+        .iter()
+        .map(|items| {
+            let lines: Vec<String> = items.iter().map(u32::to_string).collect();
+            lines.join("\n")
+        })
+        .collect();
+    groups.join("\n\n")
+}
@@ -0,0 +1,223 @@
+//! Rock paper scissors with any odd number of hands.
+//!
+//! Hands sit on a circle and each beats the half of the others just before
+//! it, so every hand beats and loses to the same number of hands. With three
+//! hands this is rock, paper, scissors; with five, in circle order, rock,
+//! Spock, paper, lizard, scissors.
+
+use aoc_common::{Error, Result};
+
+use crate::Outcome;
+
+/// A cyclic-dominance game, its hands numbered from zero around the circle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+    hands: usize,
+}
+
+impl Game {
+    /// Rock, paper, scissors.
+    pub const RPS: Game = Game { hands: 3 };
+
+    /// Rock, Spock, paper, lizard, scissors.
+    pub const RPSLS: Game = Game { hands: 5 };
+
+    /// A game of `hands` hands, which must be odd for every hand to beat as
+    /// many as it loses to.
+    pub fn new(hands: usize) -> Result<Self> {
+        match hands % 2 {
+            1 => Ok(Game { hands }),
+            _ => Err(Error::invalid(format!(
+                "a fair game needs an odd number of hands, not {hands}"
+            ))),
+        }
+    }
+
+    pub fn hands(&self) -> usize {
+        self.hands
+    }
+
+    /// `hand`, if it is one of the game's.
+    fn hand(&self, hand: usize) -> Option<usize> {
+        (hand < self.hands).then_some(hand)
+    }
+
+    /// How far `to` is past `from` going round the circle.
+    fn distance(&self, from: usize, to: usize) -> Option<usize> {
+        let (from, to) = (self.hand(from)?, self.hand(to)?);
+        Some(if to >= from {
+            to - from
+        } else {
+            self.hands - (from - to)
+        })
+    }
+
+    /// The hand `steps` past `hand`, for `steps` below the number of hands;
+    /// no sum exceeds the number of hands, however many there are.
+    fn ahead(&self, hand: usize, steps: usize) -> usize {
+        if steps < self.hands - hand {
+            hand + steps
+        } else {
+            steps - (self.hands - hand)
+        }
+    }
+
+    /// The hand `steps` before `hand`, for `steps` below the number of hands.
+    fn behind(&self, hand: usize, steps: usize) -> usize {
+        if steps <= hand {
+            hand - steps
+        } else {
+            self.hands - (steps - hand)
+        }
+    }
+
+    /// The result of playing `ours` against `theirs`, for us; `None` unless
+    /// both are hands of the game, as for every method taking hands.
+    pub fn outcome(&self, ours: usize, theirs: usize) -> Option<Outcome> {
+        Some(match self.distance(theirs, ours)? {
+            0 => Outcome::Draw,
+            ahead if ahead <= self.hands / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        })
+    }
+
+    /// The hands that `hand` beats, nearest first.
+    pub fn beats(&self, hand: usize) -> Option<impl Iterator<Item = usize> + '_> {
+        let hand = self.hand(hand)?;
+        Some((1..=self.hands / 2).map(move |steps| self.behind(hand, steps)))
+    }
+
+    /// The hands that beat `hand`, nearest first.
+    pub fn loses_to(&self, hand: usize) -> Option<impl Iterator<Item = usize> + '_> {
+        let hand = self.hand(hand)?;
+        Some((1..=self.hands / 2).map(move |steps| self.ahead(hand, steps)))
+    }
+
+    /// Every hand that gets `outcome` against `theirs`, nearest first.
+    pub fn hands_for(&self, theirs: usize, outcome: Outcome) -> Option<Vec<usize>> {
+        Some(match outcome {
+            Outcome::Draw => vec![self.hand(theirs)?],
+            Outcome::Win => self.loses_to(theirs)?.collect(),
+            Outcome::Lose => self.beats(theirs)?.collect(),
+        })
+    }
+
+    /// The hand nearest `theirs` that gets `outcome`, the only one with
+    /// three hands.
+    pub fn hand_for(&self, theirs: usize, outcome: Outcome) -> Option<usize> {
+        let theirs = self.hand(theirs)?;
+        Some(match outcome {
+            Outcome::Draw => theirs,
+            Outcome::Win => self.ahead(theirs, 1),
+            Outcome::Lose => self.behind(theirs, 1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RPSLS: [&str; 5] = ["rock", "Spock", "paper", "lizard", "scissors"];
+
+    fn named(game: &Game, hand: &str) -> usize {
+        let hand = RPSLS.iter().position(|&name| name == hand).unwrap();
+        assert!(hand < game.hands());
+        hand
+    }
+
+    #[test]
+    fn test_day2_rps_table() {
+        let game = Game::RPS;
+        let table: Vec<Vec<Outcome>> = (0..3)
+            .map(|ours| {
+                (0..3)
+                    .map(|theirs| game.outcome(ours, theirs).unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            table,
+            [
+                [Outcome::Draw, Outcome::Lose, Outcome::Win],
+                [Outcome::Win, Outcome::Draw, Outcome::Lose],
+                [Outcome::Lose, Outcome::Win, Outcome::Draw],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day2_rpsls_relations() {
+        let game = Game::RPSLS;
+        for (winner, loser) in [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "Spock"),
+            ("Spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "Spock"),
+            ("Spock", "rock"),
+            ("rock", "scissors"),
+        ] {
+            let (winner, loser) = (named(&game, winner), named(&game, loser));
+            assert_eq!(game.outcome(winner, loser), Some(Outcome::Win));
+            assert_eq!(game.outcome(loser, winner), Some(Outcome::Lose));
+            assert!(game.beats(winner).unwrap().any(|hand| hand == loser));
+            assert!(game.loses_to(loser).unwrap().any(|hand| hand == winner));
+        }
+
+        let spock = named(&game, "Spock");
+        assert_eq!(game.hands_for(spock, Outcome::Win), Some(vec![2, 3]));
+        assert_eq!(game.hand_for(spock, Outcome::Lose), Some(0));
+    }
+
+    #[test]
+    fn test_day2_seven_hands() {
+        let game = Game::new(7).unwrap();
+        for hand in 0..7 {
+            let beaten: Vec<usize> = game.beats(hand).unwrap().collect();
+            let beaten_by: Vec<usize> = game.loses_to(hand).unwrap().collect();
+            assert_eq!((beaten.len(), beaten_by.len()), (3, 3));
+            assert!(beaten.iter().all(|other| !beaten_by.contains(other)));
+            assert!(!beaten.contains(&hand) && !beaten_by.contains(&hand));
+        }
+        assert_eq!(game.beats(1).unwrap().collect::<Vec<_>>(), [0, 6, 5]);
+    }
+
+    #[test]
+    fn test_day2_game_sizes() {
+        assert_eq!(Game::new(3), Ok(Game::RPS));
+        assert_eq!(Game::new(1).unwrap().outcome(0, 0), Some(Outcome::Draw));
+        assert_eq!(
+            Game::new(4).unwrap_err().to_string(),
+            "a fair game needs an odd number of hands, not 4"
+        );
+        assert!(Game::new(0).is_err());
+
+        // The largest game works its circle out without overflowing.
+        let game = Game::new(usize::MAX).unwrap();
+        let last = usize::MAX - 1;
+        assert_eq!(game.outcome(1, 0), Some(Outcome::Win));
+        assert_eq!(game.outcome(0, 1), Some(Outcome::Lose));
+        assert_eq!(game.outcome(last, 0), Some(Outcome::Lose));
+        assert_eq!(game.outcome(0, last), Some(Outcome::Win));
+        assert_eq!(game.outcome(usize::MAX, 0), None);
+        assert_eq!(game.hand_for(last, Outcome::Win), Some(0));
+        assert_eq!(game.hand_for(0, Outcome::Lose), Some(last));
+        assert_eq!(game.beats(0).unwrap().next(), Some(last));
+        assert_eq!(game.loses_to(last).unwrap().next(), Some(0));
+    }
+
+    #[test]
+    fn test_day2_hands_out_of_range() {
+        let game = Game::RPS;
+        assert_eq!(game.outcome(5, 0), None);
+        assert_eq!(game.outcome(0, 3), None);
+        assert!(game.beats(3).is_none() && game.loses_to(3).is_none());
+        assert_eq!(game.hands_for(3, Outcome::Draw), None);
+        assert_eq!(game.hand_for(3, Outcome::Win), None);
+        assert_eq!(Game::RPSLS.outcome(4, 0), Some(Outcome::Lose));
+    }
+}
@@ -0,0 +1,226 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! Each line of the strategy guide holds the opponent's hand and a second
+//! column, read as our hand in part one and as the desired outcome in part two.
+//! Lines score independently, so [`DayTwoRayon`] spreads them over threads.
+//! Both parts are the three-hand case of the [`engine`], scored by the
+//! [`rules`] presets.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use aoc_common::{Error, Result, Solution};
+
+pub mod engine;
+pub mod rules;
+
+pub use crate::engine::Game;
+pub use crate::rules::{Play, Rules};
+
+/// A hand shape, in [`Game::RPS`] order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    const ALL: [Hand; 3] = [Hand::Rock, Hand::Paper, Hand::Scissors];
+
+    /// Number of the hand in [`Game::RPS`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The hand numbered `index` in [`Game::RPS`], if there is one.
+    pub fn from_index(index: usize) -> Option<Hand> {
+        Hand::ALL.get(index).copied()
+    }
+}
+
+/// A hand is less than the one that beats it.
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(match Game::RPS.outcome(self.index(), other.index())? {
+            Outcome::Lose => Ordering::Less,
+            Outcome::Draw => Ordering::Equal,
+            Outcome::Win => Ordering::Greater,
+        })
+    }
+}
+
+/// Parses either column's symbol for a hand in [`Rules::part_one`].
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let preset = Rules::part_one();
+        let ours = match preset.ours(s) {
+            Some(Play::Hand(hand)) => Some(hand),
+            _ => None,
+        };
+        preset
+            .theirs(s)
+            .or(ours)
+            .and_then(Hand::from_index)
+            .ok_or_else(|| Error::invalid(format!("invalid hand '{s}'")))
+    }
+}
+
+/// Result of a round for us, scored by the [`Rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+/// Parses a second-column symbol for an outcome in [`Rules::part_two`].
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Rules::part_two().ours(s) {
+            Some(Play::For(outcome)) => Ok(outcome),
+            _ => Err(Error::invalid(format!("invalid outcome '{s}'"))),
+        }
+    }
+}
+
+/// Splits a strategy guide line into its two columns.
+fn columns<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str)> {
+    line.split_once(' ')
+        .ok_or_else(|| Error::parse(input, line, "expected two columns separated by a space"))
+}
+
+/// Total score when the second column is our hand.
+pub fn part_one(input: &str) -> Result<u32> {
+    Rules::part_one().score(input)
+}
+
+/// Total score when the second column is the outcome we need.
+pub fn part_two(input: &str) -> Result<u32> {
+    Rules::part_two().score(input)
+}
+
+/// [`part_one`], scoring lines in parallel.
+pub fn part_one_par(input: &str) -> Result<u32> {
+    Rules::part_one().score_par(input)
+}
+
+/// [`part_two`], scoring lines in parallel.
+pub fn part_two_par(input: &str) -> Result<u32> {
+    Rules::part_two().score_par(input)
+}
+
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const NAME: &'static str = "Day Two";
+    const VARIANT: &'static str = "sequential";
+
+    type Parsed<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+pub struct DayTwoRayon;
+
+impl Solution for DayTwoRayon {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const NAME: &'static str = "Day Two (Rayon)";
+    const VARIANT: &'static str = "rayon";
+
+    type Parsed<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one_par(input)
+    }
+
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two_par(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn test_day2_part1_sample() {
+        assert_eq!(part_one(SAMPLE_INPUT).unwrap(), 15);
+    }
+
+    #[test]
+    fn test_day2_part2_sample() {
+        assert_eq!(part_two(SAMPLE_INPUT).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_day2_rayon_sample() {
+        assert_eq!(part_one_par(SAMPLE_INPUT).unwrap(), 15);
+        assert_eq!(part_two_par(SAMPLE_INPUT).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_day2_rayon_matches_sequential() {
+        let input = [
+            "A X", "A Y", "A Z", "B X", "B Y", "B Z", "C X", "C Y", "C Z",
+        ]
+        .repeat(500)
+        .join("\n");
+        assert_eq!(part_one_par(&input).unwrap(), part_one(&input).unwrap());
+        assert_eq!(part_two_par(&input).unwrap(), part_two(&input).unwrap());
+    }
+
+    #[test]
+    fn test_day2_hands_order_by_who_wins() {
+        assert!(Hand::Rock < Hand::Paper && Hand::Paper < Hand::Scissors);
+        assert!(Hand::Scissors < Hand::Rock);
+        assert_eq!(Hand::Paper.partial_cmp(&Hand::Paper), Some(Ordering::Equal));
+        assert_eq!(
+            Hand::from_index(Hand::Scissors.index()),
+            Some(Hand::Scissors)
+        );
+        assert_eq!(Hand::from_index(3), None);
+    }
+
+    #[test]
+    fn test_day2_parse_errors() {
+        let err = part_one("A Y\nB Q").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: invalid hand 'Q'");
+
+        let err = part_two("A Y\nC").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected two columns separated by a space"
+        );
+
+        let err = part_one_par("A Y\nB Q").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: invalid hand 'Q'");
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_2::DayTwo;
+
+fn main() {
+    run::<DayTwo>();
+}
@@ -0,0 +1,464 @@
+//! Scoring rules read from a TOML file, so that other versions of the game
+//! can be scored without recompiling:
+//!
+//! ```toml
+//! hands = ["rock", "paper", "scissors"]
+//!
+//! [shape_scores]
+//! rock = 1
+//! paper = 2
+//! scissors = 3
+//!
+//! [outcome_scores]
+//! lose = 0
+//! draw = 3
+//! win = 6
+//!
+//! [theirs]
+//! A = "rock"
+//!
+//! [ours]
+//! X = "rock"
+//! Y = "draw"
+//! ```
+//!
+//! `hands` lists the hands of a [`Game`] in circle order. `theirs` maps the
+//! symbols of the first column to hands, `ours` those of the second column to
+//! a hand to play or an outcome to play for. The two parts of the puzzle are
+//! the presets in `rules/`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use aoc_common::{Error, Result};
+use rayon::prelude::*;
+
+use crate::engine::Game;
+use crate::{columns, Outcome};
+
+const OUTCOMES: [(&str, Outcome); 3] = [
+    ("lose", Outcome::Lose),
+    ("draw", Outcome::Draw),
+    ("win", Outcome::Win),
+];
+
+/// What a symbol of the second column asks us to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Play {
+    Hand(usize),
+    For(Outcome),
+}
+
+/// A game, what its symbols stand for and how its rounds score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    game: Game,
+    hands: Vec<String>,
+    shape_scores: Vec<u32>,
+    outcome_scores: [u32; 3],
+    theirs: BTreeMap<String, usize>,
+    ours: BTreeMap<String, Play>,
+}
+
+impl Rules {
+    /// The puzzle's part one, where the second column is our hand.
+    pub fn part_one() -> &'static Rules {
+        static PRESET: OnceLock<Rules> = OnceLock::new();
+        PRESET.get_or_init(|| Rules::parse(include_str!("../rules/part_one.toml")).unwrap())
+    }
+
+    /// The puzzle's part two, where the second column is the outcome we need.
+    pub fn part_two() -> &'static Rules {
+        static PRESET: OnceLock<Rules> = OnceLock::new();
+        PRESET.get_or_init(|| Rules::parse(include_str!("../rules/part_two.toml")).unwrap())
+    }
+
+    /// Reads the rules file at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| Error::invalid(format!("{}: {err}", path.display())))?;
+        Self::from_toml(&text)
+            .map_err(|message| Error::invalid(format!("{}: {message}", path.display())))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        Self::from_toml(text).map_err(Error::invalid)
+    }
+
+    fn from_toml(text: &str) -> std::result::Result<Self, String> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| err.to_string())?;
+        if let Some(key) = table.keys().find(|key| {
+            !["hands", "shape_scores", "outcome_scores", "theirs", "ours"].contains(&key.as_str())
+        }) {
+            return Err(format!("unknown key '{key}'"));
+        }
+
+        let hands = parse_hands(&table)?;
+        let game = Game::new(hands.len()).map_err(|err| format!("hands: {err}"))?;
+        let hand = |name: &str| hands.iter().position(|hand| hand == name);
+
+        let mut shape_scores = vec![None; hands.len()];
+        for (name, score) in section(&table, "shape_scores")? {
+            let hand = hand(name).ok_or_else(|| format!("shape_scores.{name} is not a hand"))?;
+            shape_scores[hand] = Some(parse_score("shape_scores", name, score)?);
+        }
+        let shape_scores = (0..hands.len())
+            .map(|hand| {
+                shape_scores[hand].ok_or_else(|| format!("shape_scores.{} is missing", hands[hand]))
+            })
+            .collect::<std::result::Result<_, _>>()?;
+
+        let outcomes = section(&table, "outcome_scores")?;
+        if let Some(name) = outcomes
+            .keys()
+            .find(|&name| OUTCOMES.iter().all(|(outcome, _)| outcome != name))
+        {
+            return Err(format!("outcome_scores.{name} is not lose, draw or win"));
+        }
+        let mut outcome_scores = [0; 3];
+        for (score, (name, _)) in outcome_scores.iter_mut().zip(OUTCOMES) {
+            let value = outcomes
+                .get(name)
+                .ok_or_else(|| format!("outcome_scores.{name} is missing"))?;
+            *score = parse_score("outcome_scores", name, value)?;
+        }
+
+        let mut theirs = BTreeMap::new();
+        for (symbol, meaning) in symbols(&table, "theirs")? {
+            let hand = hand(meaning)
+                .ok_or_else(|| format!("theirs.{symbol} should name a hand, not '{meaning}'"))?;
+            theirs.insert(symbol.clone(), hand);
+        }
+
+        let mut ours = BTreeMap::new();
+        for (symbol, meaning) in symbols(&table, "ours")? {
+            let play = match hand(meaning) {
+                Some(hand) => Play::Hand(hand),
+                None => OUTCOMES
+                    .iter()
+                    .find(|&&(name, _)| name == meaning)
+                    .map(|&(_, outcome)| Play::For(outcome))
+                    .ok_or_else(|| {
+                        format!("ours.{symbol} should name a hand or outcome, not '{meaning}'")
+                    })?,
+            };
+            ours.insert(symbol.clone(), play);
+        }
+
+        Ok(Rules {
+            game,
+            hands,
+            shape_scores,
+            outcome_scores,
+            theirs,
+            ours,
+        })
+    }
+
+    pub fn game(&self) -> Game {
+        self.game
+    }
+
+    /// Name of the hand numbered `hand`, if the game has one.
+    pub fn hand_name(&self, hand: usize) -> Option<&str> {
+        self.hands.get(hand).map(String::as_str)
+    }
+
+    /// The hand a first-column symbol stands for.
+    pub fn theirs(&self, symbol: &str) -> Option<usize> {
+        self.theirs.get(symbol).copied()
+    }
+
+    /// What a second-column symbol asks us to play.
+    pub fn ours(&self, symbol: &str) -> Option<Play> {
+        self.ours.get(symbol).copied()
+    }
+
+    /// What the second column holds, for error messages.
+    fn ours_kind(&self) -> &'static str {
+        let hands = self
+            .ours
+            .values()
+            .filter(|play| matches!(play, Play::Hand(_)))
+            .count();
+        match hands {
+            0 => "outcome",
+            n if n == self.ours.len() => "hand",
+            _ => "hand or outcome",
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        let index = OUTCOMES.iter().position(|&(_, o)| o == outcome).unwrap();
+        self.outcome_scores[index]
+    }
+
+    /// Error for a hand number that is not one of the game's.
+    fn not_a_hand(&self, hand: usize) -> Error {
+        Error::invalid(format!("no hand {hand} in a game of {}", self.game.hands()))
+    }
+
+    /// Score of a round where we play `ours` against `theirs`.
+    pub fn round_score(&self, ours: usize, theirs: usize) -> Result<u32> {
+        let outcome = self
+            .game
+            .outcome(ours, theirs)
+            .ok_or_else(|| self.not_a_hand(ours.max(theirs)))?;
+        self.shape_scores[ours]
+            .checked_add(self.outcome_score(outcome))
+            .ok_or_else(|| Error::overflow("round score overflows u32"))
+    }
+
+    /// Score of a line of `input`. With more than three hands, playing for an
+    /// outcome picks the nearest hand that gets it.
+    pub fn score_line(&self, input: &str, line: &str) -> Result<u32> {
+        let (left, right) = columns(input, line)?;
+        let theirs = self
+            .theirs(left)
+            .ok_or_else(|| Error::parse(input, left, format!("invalid hand '{left}'")))?;
+        let ours = match self.ours(right) {
+            Some(Play::Hand(hand)) => hand,
+            Some(Play::For(outcome)) => self
+                .game
+                .hand_for(theirs, outcome)
+                .ok_or_else(|| self.not_a_hand(theirs))?,
+            None => {
+                let kind = self.ours_kind();
+                return Err(Error::parse(
+                    input,
+                    right,
+                    format!("invalid {kind} '{right}'"),
+                ));
+            }
+        };
+        self.round_score(ours, theirs)
+            .map_err(|err| err.at(input, line))
+    }
+
+    /// Total score of a strategy guide.
+    pub fn score(&self, input: &str) -> Result<u32> {
+        input
+            .lines()
+            .map(|line| self.score_line(input, line))
+            .try_fold(0, |total, score| add_scores(total, score?))
+    }
+
+    /// [`Rules::score`], scoring lines in parallel.
+    pub fn score_par(&self, input: &str) -> Result<u32> {
+        input
+            .par_lines()
+            .map(|line| self.score_line(input, line))
+            .try_reduce(|| 0, add_scores)
+    }
+}
+
+fn add_scores(total: u32, score: u32) -> Result<u32> {
+    total
+        .checked_add(score)
+        .ok_or_else(|| Error::overflow("total score overflows u32"))
+}
+
+fn parse_hands(table: &toml::Table) -> std::result::Result<Vec<String>, String> {
+    let names = table
+        .get("hands")
+        .ok_or("hands is missing")?
+        .as_array()
+        .ok_or("hands should be an array of names")?;
+    let mut hands: Vec<String> = vec![];
+    for name in names {
+        let name = name.as_str().ok_or("hands should be an array of names")?;
+        if hands.iter().any(|hand| hand == name) {
+            return Err(format!("hands lists '{name}' twice"));
+        }
+        if OUTCOMES.iter().any(|&(outcome, _)| outcome == name) {
+            return Err(format!("hands: '{name}' is the name of an outcome"));
+        }
+        hands.push(name.to_string());
+    }
+    Ok(hands)
+}
+
+fn section<'a>(table: &'a toml::Table, key: &str) -> std::result::Result<&'a toml::Table, String> {
+    table
+        .get(key)
+        .ok_or_else(|| format!("[{key}] is missing"))?
+        .as_table()
+        .ok_or_else(|| format!("[{key}] should be a table"))
+}
+
+fn parse_score(key: &str, name: &str, value: &toml::Value) -> std::result::Result<u32, String> {
+    value
+        .as_integer()
+        .and_then(|score| u32::try_from(score).ok())
+        .ok_or_else(|| format!("{key}.{name} should be a score from 0 to {}", u32::MAX))
+}
+
+/// The symbols of a column and the names they stand for.
+fn symbols<'a>(
+    table: &'a toml::Table,
+    key: &str,
+) -> std::result::Result<Vec<(&'a String, &'a str)>, String> {
+    section(table, key)?
+        .iter()
+        .map(|(symbol, meaning)| {
+            if symbol.is_empty() || symbol.contains(char::is_whitespace) {
+                return Err(format!("{key}: '{symbol}' can't be told apart in a line"));
+            }
+            let meaning = meaning
+                .as_str()
+                .ok_or_else(|| format!("{key}.{symbol} should be a name"))?;
+            Ok((symbol, meaning))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn test_day2_presets() {
+        assert_eq!(Rules::part_one().score(SAMPLE_INPUT).unwrap(), 15);
+        assert_eq!(Rules::part_two().score(SAMPLE_INPUT).unwrap(), 12);
+        assert_eq!(Rules::part_one().score_par(SAMPLE_INPUT).unwrap(), 15);
+
+        // Paper against rock, rock against paper, scissors against scissors.
+        assert_eq!(Rules::part_one().round_score(1, 0).unwrap(), 8);
+        assert_eq!(Rules::part_one().round_score(0, 1).unwrap(), 1);
+        assert_eq!(Rules::part_one().round_score(2, 2).unwrap(), 6);
+
+        assert_eq!(Rules::part_one().game(), Game::RPS);
+        assert_eq!(Rules::part_one().ours("Y"), Some(Play::Hand(1)));
+        assert_eq!(Rules::part_two().ours("Y"), Some(Play::For(Outcome::Draw)));
+        assert_eq!(Rules::part_two().theirs("C"), Some(2));
+        assert_eq!(Rules::part_two().theirs("Z"), None);
+    }
+
+    #[test]
+    fn test_day2_rpsls_tournament() {
+        let rules = Rules::parse(include_str!("../rules/rpsls.toml")).unwrap();
+        assert_eq!(rules.game(), Game::RPSLS);
+        assert_eq!(rules.hand_name(rules.theirs("K").unwrap()), Some("Spock"));
+        assert_eq!(rules.hand_name(5), None);
+
+        // Spock vaporizes rock, lizard poisons Spock, Spock ties Spock.
+        assert_eq!(rules.score("R K\nK L\nK K").unwrap(), 4 + 4 + 2);
+        assert_eq!(rules.round_score(4, 0).unwrap(), 1);
+        assert_eq!(
+            rules.round_score(5, 0).unwrap_err().to_string(),
+            "no hand 5 in a game of 5"
+        );
+        let err = rules.score("R K\nR X").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: invalid hand 'X'");
+    }
+
+    #[test]
+    fn test_day2_mixed_second_column() {
+        let rules = Rules::parse(
+            &include_str!("../rules/part_one.toml").replace("Z = \"scissors\"", "Z = \"win\""),
+        )
+        .unwrap();
+        // Paper against rock, then scissors to beat paper.
+        assert_eq!(rules.score("A Y\nB Z").unwrap(), 8 + 9);
+        let err = rules.score("A Q").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: invalid hand or outcome 'Q'"
+        );
+        let err = Rules::part_two().score("A Q").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: invalid outcome 'Q'");
+    }
+
+    #[test]
+    fn test_day2_score_overflow() {
+        let rules = Rules::parse(
+            &include_str!("../rules/part_one.toml").replace("win = 6", "win = 4294967290"),
+        )
+        .unwrap();
+        assert_eq!(rules.score("A Y").unwrap(), 4294967292);
+        let err = rules.score("A Y\nA Y").unwrap_err();
+        assert_eq!(err.to_string(), "total score overflows u32");
+        assert!(matches!(err, Error::Overflow(_)));
+        let err = rules.score_par("A Y\nA Y").unwrap_err();
+        assert_eq!(err.to_string(), "total score overflows u32");
+        let err = rules.score("A A\nC Y").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: invalid hand 'A'");
+
+        let rules = Rules::parse(
+            &include_str!("../rules/part_one.toml")
+                .replace("scissors = 3", "scissors = 4294967295"),
+        )
+        .unwrap();
+        let err = rules.score("A Y\nC Z").unwrap_err();
+        assert_eq!(err.to_string(), "round score overflows u32");
+        assert!(matches!(err, Error::Overflow(_)));
+    }
+
+    #[test]
+    fn test_day2_rules_errors() {
+        let preset = include_str!("../rules/part_one.toml");
+        for (from, to, message) in [
+            ("hands = [", "hand = [", "unknown key 'hand'"),
+            (
+                "\"scissors\"]",
+                "\"scissors\", \"well\"]",
+                "hands: a fair game needs an odd number of hands, not 4",
+            ),
+            (
+                "\"paper\", \"scissors\"]",
+                "\"paper\", \"rock\"]",
+                "hands lists 'rock' twice",
+            ),
+            (
+                "\"scissors\"]",
+                "\"win\"]",
+                "hands: 'win' is the name of an outcome",
+            ),
+            (
+                "scissors = 3",
+                "scissors = -3",
+                "shape_scores.scissors should be a score from 0 to 4294967295",
+            ),
+            ("scissors = 3", "", "shape_scores.scissors is missing"),
+            (
+                "scissors = 3",
+                "stone = 3",
+                "shape_scores.stone is not a hand",
+            ),
+            (
+                "win = 6",
+                "tie = 6",
+                "outcome_scores.tie is not lose, draw or win",
+            ),
+            (
+                "[theirs]\nA = \"rock\"",
+                "[theirs]\nA = \"stone\"",
+                "theirs.A should name a hand, not 'stone'",
+            ),
+            (
+                "[ours]\nX = \"rock\"",
+                "[ours]\nX = \"tie\"",
+                "ours.X should name a hand or outcome, not 'tie'",
+            ),
+            (
+                "[ours]\nX = \"rock\"",
+                "[ours]\n\"X X\" = \"rock\"",
+                "ours: 'X X' can't be told apart in a line",
+            ),
+            ("[ours]", "[mine]", "unknown key 'mine'"),
+        ] {
+            assert!(preset.contains(from), "{from}");
+            let err = Rules::parse(&preset.replace(from, to)).unwrap_err();
+            assert_eq!(err.to_string(), message);
+        }
+
+        let err = Rules::load(Path::new("no/such/rules.toml")).unwrap_err();
+        assert!(err.to_string().starts_with("no/such/rules.toml: "));
+    }
+}
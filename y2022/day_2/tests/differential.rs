@@ -0,0 +1,25 @@
+//! Cross-checks the sequential and rayon variants on generated strategy
+//! guides; proptest shrinks any disagreement down to a minimal input.
+
+mod strategies;
+
+use aoc_common::{cross_check, Entry};
+use day_2::{DayTwo, DayTwoRayon};
+use proptest::prelude::*;
+use strategies::{render, rounds};
+
+fn variants() -> [Entry; 2] {
+    [Entry::of::<DayTwo>(), Entry::of::<DayTwoRayon>()]
+}
+
+proptest! {
+    #[test]
+    fn test_day2_variants_agree(rounds in rounds()) {
+        prop_assert_eq!(cross_check(&render(&rounds), &variants()), Ok(()));
+    }
+
+    #[test]
+    fn test_day2_variants_agree_on_noise(input in "[ABCXYZ \n]{0,40}") {
+        prop_assert_eq!(cross_check(&input, &variants()), Ok(()));
+    }
+}
@@ -0,0 +1,11 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day2.txt`.
+
+#[test]
+fn test_day2_example_daytwo() {
+    aoc_common::assert_example::<day_2::DayTwo>();
+}
+
+#[test]
+fn test_day2_example_daytworayon() {
+    aoc_common::assert_example::<day_2::DayTwoRayon>();
+}
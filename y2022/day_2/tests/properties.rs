@@ -0,0 +1,77 @@
+//! Invariants of the scores on generated strategy guides.
+
+mod strategies;
+
+use day_2::{part_one, part_two, Game, Outcome};
+use proptest::prelude::*;
+use strategies::{render, rounds};
+
+/// A game of up to 15 hands and two hands of it, theirs then ours.
+fn games() -> impl Strategy<Value = (Game, usize, usize)> {
+    (0..8usize).prop_flat_map(|half| {
+        let hands = 2 * half + 1;
+        (Just(Game::new(hands).unwrap()), 0..hands, 0..hands)
+    })
+}
+
+/// Index of a column letter from its first letter, 0 for rock or a loss.
+fn index(letter: char, first: char) -> u8 {
+    letter as u8 - first as u8
+}
+
+proptest! {
+    #[test]
+    fn test_day2_rounds_score_between_one_and_nine(rounds in rounds()) {
+        let n = rounds.len() as u32;
+        for score in [part_one(&render(&rounds)).unwrap(), part_two(&render(&rounds)).unwrap()] {
+            prop_assert!(n <= score && score <= 9 * n);
+        }
+    }
+
+    #[test]
+    fn test_day2_round_order_does_not_matter(mut rounds in rounds()) {
+        let before = (part_one(&render(&rounds)).unwrap(), part_two(&render(&rounds)).unwrap());
+        rounds.reverse();
+        let after = (part_one(&render(&rounds)).unwrap(), part_two(&render(&rounds)).unwrap());
+        prop_assert_eq!(before, after);
+    }
+
+    /// Playing for an outcome scores the same as playing the hand that gets it.
+    #[test]
+    fn test_day2_playbook_is_playing_the_chosen_hands(rounds in rounds()) {
+        let chosen: Vec<(char, char)> = rounds
+            .iter()
+            .map(|&(theirs, outcome)| {
+                // Losing plays the hand one before theirs, winning the one after.
+                let hand = (index(theirs, 'A') + index(outcome, 'X') + 2) % 3;
+                (theirs, (b'X' + hand) as char)
+            })
+            .collect();
+        prop_assert_eq!(part_two(&render(&rounds)).unwrap(), part_one(&render(&chosen)).unwrap());
+    }
+
+    /// Whatever one hand gets against another, the other gets the reverse.
+    #[test]
+    fn test_day2_outcomes_are_antisymmetric((game, theirs, ours) in games()) {
+        let reversed = match game.outcome(ours, theirs).unwrap() {
+            Outcome::Win => Outcome::Lose,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Lose => Outcome::Win,
+        };
+        prop_assert_eq!(game.outcome(theirs, ours), Some(reversed));
+        prop_assert_eq!(game.beats(ours).unwrap().count(), game.hands() / 2);
+        prop_assert_eq!(game.beats(ours).unwrap().any(|hand| hand == theirs), game.outcome(ours, theirs) == Some(Outcome::Win));
+        prop_assert_eq!(game.outcome(game.hands() + ours, theirs), None);
+    }
+
+    /// Choosing a hand for an outcome is the inverse of playing it.
+    #[test]
+    fn test_day2_hand_for_inverts_outcome((game, theirs, ours) in games()) {
+        let outcome = game.outcome(ours, theirs).unwrap();
+        let hand = game.hand_for(theirs, outcome).unwrap();
+        prop_assert_eq!(game.outcome(hand, theirs), Some(outcome));
+        let choices = game.hands_for(theirs, outcome).unwrap();
+        prop_assert!(choices.contains(&ours));
+        prop_assert_eq!(choices[0], hand);
+    }
+}
@@ -0,0 +1,21 @@
+//! Generators of valid strategy guides.
+
+use proptest::prelude::*;
+
+/// Rounds of the guide, the opponent's `A`-`C` and our `X`-`Z` column, long
+/// enough to be split across threads.
+pub fn rounds() -> impl Strategy<Value = Vec<(char, char)>> {
+    prop::collection::vec(
+        (prop::char::range('A', 'C'), prop::char::range('X', 'Z')),
+        0..500,
+    )
+}
+
+/// The guide as the puzzle writes it, one `A X` round per line.
+pub fn render(rounds: &[(char, char)]) -> String {
+    let lines: Vec<String> = rounds
+        .iter()
+        .map(|(theirs, ours)| format!("{theirs} {ours}"))
+        .collect();
+    lines.join("\n")
+}
@@ -0,0 +1,132 @@
+//! Day 3: Rucksack Reorganization.
+//!
+//! Each line is a rucksack whose two halves are its compartments; items are
+//! letters, prioritised `a..=z` as 1 to 26 and `A..=Z` as 27 to 52.
+
+use std::collections::HashMap;
+
+use aoc_common::{Error, Result, Solution};
+
+/// Priority of every item type.
+pub fn create_value_map() -> HashMap<char, usize> {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .enumerate()
+        .map(|(idx, c)| (c, idx + 1))
+        .collect()
+}
+
+fn item_value(
+    value_map: &HashMap<char, usize>,
+    input: &str,
+    sack: &str,
+    item: char,
+) -> Result<usize> {
+    value_map
+        .get(&item)
+        .copied()
+        .ok_or_else(|| Error::parse(input, sack, format!("invalid item '{item}'")))
+}
+
+/// Sum of the priorities of the item found in both compartments of each rucksack.
+pub fn part_one(input: &str) -> Result<usize> {
+    let value_map = create_value_map();
+
+    input
+        .lines()
+        .map(|sack| {
+            let half = sack.len() / 2;
+
+            let half_left: Vec<char> = sack.chars().take(half).collect();
+            let half_right: Vec<char> = sack.chars().skip(half).collect();
+            let shared_item = half_left
+                .iter()
+                .find(|&c| half_right.contains(c))
+                .ok_or_else(|| Error::parse(input, sack, "no item shared by both compartments"))?;
+            item_value(&value_map, input, sack, *shared_item)
+        })
+        .sum()
+}
+
+/// Sum of the priorities of the badge shared by each group of three rucksacks.
+pub fn part_two(input: &str) -> Result<usize> {
+    let value_map = create_value_map();
+
+    let mut p2_total = 0;
+    let mut sacks = input.lines();
+    while let (Some(sack_a), Some(sack_b), Some(sack_c)) =
+        (sacks.next(), sacks.next(), sacks.next())
+    {
+        let shared_item = sack_a
+            .chars()
+            .find(|&c| sack_b.contains(c) && sack_c.contains(c))
+            .ok_or_else(|| Error::parse(input, sack_a, "no badge shared by the group"))?;
+        p2_total += item_value(&value_map, input, sack_a, shared_item)?
+    }
+    Ok(p2_total)
+}
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const NAME: &'static str = "Day Three";
+
+    type Parsed<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_map() {
+        let value_map = create_value_map();
+
+        assert_eq!(value_map[&'a'], 1);
+        assert_eq!(value_map[&'z'], 26);
+        assert_eq!(value_map[&'A'], 27);
+        assert_eq!(value_map[&'Z'], 52);
+    }
+
+    const SAMPLE_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn test_day3_part1_sample() {
+        assert_eq!(part_one(SAMPLE_INPUT).unwrap(), 157);
+    }
+
+    #[test]
+    fn test_day3_part2_sample() {
+        assert_eq!(part_two(SAMPLE_INPUT).unwrap(), 70);
+    }
+
+    #[test]
+    fn test_day3_no_shared_item() {
+        let err = part_one("vJrwpWtwJgWrhcsFMMfFFhFp\nabcdef").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: no item shared by both compartments"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_3::DayThree;
+
+fn main() {
+    run::<DayThree>();
+}
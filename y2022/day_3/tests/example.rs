@@ -0,0 +1,6 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day3.txt`.
+
+#[test]
+fn test_day3_example() {
+    aoc_common::assert_example::<day_3::DayThree>();
+}
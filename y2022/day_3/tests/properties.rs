@@ -0,0 +1,42 @@
+//! Invariants of the priorities on generated rucksacks.
+
+mod strategies;
+
+use day_3::{create_value_map, part_one, part_two};
+use proptest::prelude::*;
+use strategies::{groups, render};
+
+proptest! {
+    #[test]
+    fn test_day3_part_one_finds_the_shared_items(groups in groups()) {
+        let priority = create_value_map();
+        let expected: usize = groups
+            .iter()
+            .flat_map(|group| group.shared)
+            .map(|item| priority[&item])
+            .sum();
+        prop_assert_eq!(part_one(&render(&groups)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_day3_part_two_finds_the_badges(groups in groups()) {
+        let priority = create_value_map();
+        let expected: usize = groups.iter().map(|group| priority[&group.badge]).sum();
+        prop_assert_eq!(part_two(&render(&groups)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_day3_rucksacks_score_between_1_and_52(groups in groups()) {
+        let total = part_one(&render(&groups)).unwrap();
+        let sacks = 3 * groups.len();
+        prop_assert!(sacks <= total && total <= 52 * sacks);
+    }
+
+    #[test]
+    fn test_day3_group_order_does_not_matter(mut groups in groups()) {
+        let before = (part_one(&render(&groups)).unwrap(), part_two(&render(&groups)).unwrap());
+        groups.reverse();
+        let after = (part_one(&render(&groups)).unwrap(), part_two(&render(&groups)).unwrap());
+        prop_assert_eq!(before, after);
+    }
+}
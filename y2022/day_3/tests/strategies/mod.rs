@@ -0,0 +1,71 @@
+//! Generators of rucksacks with exactly one item in both compartments and
+//! groups of three with exactly one badge.
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// Three rucksacks with the item each keeps in both halves and the group's
+/// badge.
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub sacks: [String; 3],
+    pub shared: [char; 3],
+    pub badge: char,
+}
+
+/// Layout of one rucksack: whether the badge is its shared item, how many
+/// other item types go in each half and how far to rotate each half.
+type Layout = (bool, usize, usize, Index);
+
+/// Packs one rucksack from `pool`, 17 item types no other rucksack of the
+/// group uses: the first can be shared by both halves, the next eight only go
+/// left and the last eight only go right.
+fn pack(pool: &[char], badge: char, (badge_shared, left, right, turn): Layout) -> (String, char) {
+    let shared = if badge_shared { badge } else { pool[0] };
+    let mut halves = [vec![shared], vec![shared]];
+    halves[0].extend(&pool[1..1 + left]);
+    halves[1].extend(&pool[9..9 + right]);
+    if !badge_shared {
+        halves[0].push(badge);
+    }
+    // Repeats inside a half keep the halves the same length.
+    let len = halves[0].len().max(halves[1].len());
+    for half in &mut halves {
+        half.resize(len, half[0]);
+        half.rotate_left(turn.index(len));
+    }
+    (halves.concat().into_iter().collect(), shared)
+}
+
+/// One group of rucksacks, each with its own item types besides the badge.
+pub fn group() -> impl Strategy<Value = Group> {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let layout = (any::<bool>(), 0..8usize, 0..8usize, any::<Index>());
+    (Just(items).prop_shuffle(), prop::array::uniform3(layout)).prop_map(|(items, layouts)| {
+        let badge = items[0];
+        let packed: Vec<(String, char)> = layouts
+            .into_iter()
+            .zip(items[1..].chunks(17))
+            .map(|(layout, pool)| pack(pool, badge, layout))
+            .collect();
+        Group {
+            sacks: [0, 1, 2].map(|i| packed[i].0.clone()),
+            shared: [0, 1, 2].map(|i| packed[i].1),
+            badge,
+        }
+    })
+}
+
+/// Rucksack lists, a whole number of groups long.
+pub fn groups() -> impl Strategy<Value = Vec<Group>> {
+    prop::collection::vec(group(), 0..20)
+}
+
+/// The rucksacks as the puzzle writes them, one per line.
+pub fn render(groups: &[Group]) -> String {
+    let sacks: Vec<&str> = groups
+        .iter()
+        .flat_map(|group| group.sacks.iter().map(String::as_str))
+        .collect();
+    sacks.join("\n")
+}
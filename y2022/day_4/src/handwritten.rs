@@ -0,0 +1,111 @@
+//! Splits each line by hand, inside the parts themselves.
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{Error, Result, Solution};
+
+use crate::{range_contains, range_intersects, RangePair};
+
+pub struct DayFour;
+
+impl Solution for DayFour {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const NAME: &'static str = "Day Four";
+    const VARIANT: &'static str = "handwritten";
+
+    type Parsed<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+fn parse_sections(input: &str, assn: &str) -> Result<RangeInclusive<u16>> {
+    let parse_num = |num: &str, what: &str| {
+        num.parse::<u16>()
+            .map_err(|_| Error::parse(input, num, format!("invalid section {what} '{num}'")))
+    };
+
+    let (start, end) = assn.split_once('-').ok_or_else(|| {
+        Error::parse(
+            input,
+            &assn[assn.len()..],
+            "expected '-' after section start",
+        )
+    })?;
+    Ok(parse_num(start, "start")?..=parse_num(end, "end")?)
+}
+
+fn parse_pair(input: &str, line: &str) -> Result<RangePair> {
+    let (a, b) = line.split_once(',').ok_or_else(|| {
+        Error::parse(
+            input,
+            &line[line.len()..],
+            "expected ',' between assignments",
+        )
+    })?;
+    Ok((parse_sections(input, a)?, parse_sections(input, b)?))
+}
+
+/// Number of pairs where one assignment fully contains the other.
+pub fn part_one(input: &str) -> Result<usize> {
+    input.trim_end().lines().try_fold(0, |total, line| {
+        let (a, b) = parse_pair(input, line)?;
+        Ok(total + range_contains(&a, &b) as usize)
+    })
+}
+
+/// Number of pairs whose assignments overlap.
+pub fn part_two(input: &str) -> Result<usize> {
+    input.trim_end().lines().try_fold(0, |total, line| {
+        let (a, b) = parse_pair(input, line)?;
+        Ok(total + range_intersects(&a, &b) as usize)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn test_day4_part1_sample() {
+        let sample_total = part_one(SAMPLE_INPUT).unwrap();
+        assert_eq!(sample_total, 2);
+    }
+
+    #[test]
+    fn test_day4_part2_sample() {
+        let sample_total = part_two(SAMPLE_INPUT).unwrap();
+        assert_eq!(sample_total, 4);
+    }
+
+    #[test]
+    fn test_day4_parse_errors() {
+        let err = part_one("2-4,6-8\n2,4-5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected '-' after section start"
+        );
+
+        let err = part_two("2-4,6-8\n2-3,4-x").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 7: invalid section end 'x'");
+    }
+}
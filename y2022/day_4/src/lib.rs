@@ -0,0 +1,62 @@
+//! Day 4: Camp Cleanup.
+//!
+//! Each line pairs two elves' section assignments, `<start>-<end>,<start>-<end>`.
+//! Two variants share the range logic below: [`DayFour`] splits the lines by
+//! hand as it goes, [`DayFourNom`] parses the whole input up front with nom.
+
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+
+pub mod handwritten;
+pub mod with_nom;
+
+pub use handwritten::DayFour;
+pub use with_nom::DayFourNom;
+
+/// Section assignments of one pair of elves.
+pub type RangePair = (RangeInclusive<u16>, RangeInclusive<u16>);
+
+/// Whether one range fully contains the other, in either direction.
+pub fn range_contains(a: &RangeInclusive<u16>, b: &RangeInclusive<u16>) -> bool {
+    match a.start().cmp(b.start()) {
+        Ordering::Less => a.end() >= b.end(),
+        Ordering::Equal => true,
+        Ordering::Greater => b.end() >= a.end(),
+    }
+}
+
+/// Whether the ranges share at least one section.
+pub fn range_intersects(a: &RangeInclusive<u16>, b: &RangeInclusive<u16>) -> bool {
+    match a.start().cmp(b.start()) {
+        Ordering::Less => a.end() >= b.start(),
+        Ordering::Equal => true,
+        Ordering::Greater => b.end() >= a.start(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day4_range_contains() {
+        // true
+        assert!(range_contains(&(1..=4), &(2..=3)));
+        assert!(range_contains(&(1..=8), &(3..=6)));
+        assert!(range_contains(&(5..=8), &(2..=9)));
+        assert!(range_contains(&(1..=4), &(1..=4)));
+
+        // false
+        assert!(!range_contains(&(1..=4), &(2..=5)));
+        assert!(!range_contains(&(1..=4), &(4..=5)));
+        assert!(!range_contains(&(4..=10), &(1..=4)));
+    }
+
+    #[test]
+    fn test_day4_range_intersects() {
+        assert!(range_intersects(&(5..=7), &(7..=9)));
+        assert!(range_intersects(&(6..=6), &(4..=6)));
+        assert!(!range_intersects(&(2..=4), &(6..=8)));
+        assert!(!range_intersects(&(4..=5), &(2..=3)));
+    }
+}
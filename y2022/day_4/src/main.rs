@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_4::DayFour;
+
+fn main() {
+    run::<DayFour>();
+}
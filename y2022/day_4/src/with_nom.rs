@@ -0,0 +1,147 @@
+//! Parses the whole input up front into pairs of section ranges with nom.
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{ensure_consumed, Error, Result, Solution};
+use nom::bytes::complete::tag;
+use nom::character::complete::{self, newline};
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+use crate::{range_contains, range_intersects, RangePair};
+
+/// Nom parsing taken from Chris Biscardi
+/// <https://github.com/ChristopherBiscardi/advent-of-code/blob/main/2022/rust/day-04/src/lib.rs>
+pub type RangeList = Vec<RangePair>;
+
+fn parse_sections(input: &str) -> IResult<&str, RangeInclusive<u16>> {
+    let (input, (start, end)) = separated_pair(complete::u16, tag("-"), complete::u16)(input)?;
+
+    Ok((input, start..=end))
+}
+
+fn parse_line(input: &str) -> IResult<&str, RangePair> {
+    let (input, (start, end)) = separated_pair(parse_sections, tag(","), parse_sections)(input)?;
+
+    Ok((input, (start, end)))
+}
+
+fn parse_line_assignments(input: &str) -> IResult<&str, RangeList> {
+    let (input, ranges) = separated_list0(newline, parse_line)(input)?;
+
+    Ok((input, ranges))
+}
+
+pub struct DayFourNom;
+
+impl Solution for DayFourNom {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const NAME: &'static str = "Day Four (Nom)";
+    const VARIANT: &'static str = "nom";
+
+    type Parsed<'a> = RangeList;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse(input)
+    }
+
+    fn part_one(assignment_pairs: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        Ok(part_one(assignment_pairs))
+    }
+
+    fn part_two(assignment_pairs: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        Ok(part_two(assignment_pairs))
+    }
+}
+
+const LINE_FORMAT: &str = "expected '<start>-<end>,<start>-<end>'";
+
+/// Parses every line into a pair of section ranges.
+pub fn parse(input: &str) -> Result<RangeList> {
+    let (rest, assignment_pairs) =
+        parse_line_assignments(input).map_err(|err| Error::from_nom(input, err, LINE_FORMAT))?;
+    ensure_consumed(input, rest, parse_line, LINE_FORMAT)?;
+    Ok(assignment_pairs)
+}
+
+/// Number of pairs where one assignment fully contains the other.
+pub fn part_one(assignment_pairs: &[RangePair]) -> usize {
+    assignment_pairs
+        .iter()
+        .filter(|(range_a, range_b)| range_contains(range_a, range_b))
+        .count()
+}
+
+/// Number of pairs whose assignments overlap.
+pub fn part_two(assignment_pairs: &[RangePair]) -> usize {
+    assignment_pairs
+        .iter()
+        .filter(|(range_a, range_b)| range_intersects(range_a, range_b))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::local_input;
+
+    const SAMPLE_INPUT: &str = "2-4,6-8\n\
+                                2-3,4-5\n\
+                                5-7,7-9\n\
+                                2-8,3-7\n\
+                                6-6,4-6\n\
+                                2-6,4-8";
+
+    #[test]
+    fn test_day4_nom_part1_sample() {
+        let sample_total = part_one(&parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(sample_total, 2);
+    }
+
+    #[test]
+    fn test_day4_nom_part2_sample() {
+        let sample_total = part_two(&parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(sample_total, 4);
+    }
+
+    #[test]
+    fn test_day4_nom_parse_errors() {
+        let err = parse("2-4,6-8\n2-3;4-5\n5-7,7-9").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected '<start>-<end>,<start>-<end>'"
+        );
+
+        let err = parse("2-4,6-8 9").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 8: expected '<start>-<end>,<start>-<end>'"
+        );
+
+        let err = parse("x-4,6-8").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected '<start>-<end>,<start>-<end>'"
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day4.txt"]
+    fn test_day4_nom_part1_actual() {
+        let input = local_input(2022, 4).unwrap();
+        let actual_total = part_one(&parse(&input).unwrap());
+        assert_eq!(actual_total, 444);
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day4.txt"]
+    fn test_day4_nom_part2_actual() {
+        let input = local_input(2022, 4).unwrap();
+        let actual_total = part_two(&parse(&input).unwrap());
+        assert_eq!(actual_total, 801);
+    }
+}
@@ -0,0 +1,34 @@
+//! Cross-checks the handwritten and nom parsers on generated assignment lists;
+//! proptest shrinks any disagreement down to a minimal input.
+
+mod strategies;
+
+use aoc_common::{cross_check, Entry};
+use day_4::{DayFour, DayFourNom};
+use proptest::prelude::*;
+use strategies::{pairs, render};
+
+fn variants() -> [Entry; 2] {
+    [Entry::of::<DayFour>(), Entry::of::<DayFourNom>()]
+}
+
+proptest! {
+    #[test]
+    fn test_day4_variants_agree(pairs in pairs(), trailing in any::<bool>()) {
+        prop_assert_eq!(cross_check(&render(&pairs, trailing), &variants()), Ok(()));
+    }
+
+    #[test]
+    fn test_day4_variants_agree_on_noise(input in "[0-9,\\-\n]{0,30}") {
+        prop_assert_eq!(cross_check(&input, &variants()), Ok(()));
+    }
+}
+
+/// Inputs the properties above once shrank to: blank input and trailing blank
+/// lines, which only the nom parser used to accept.
+#[test]
+fn test_day4_variants_agree_on_blank_lines() {
+    for input in ["", "\n", "2-4,6-8\n\n"] {
+        assert_eq!(cross_check(input, &variants()), Ok(()), "{input:?}");
+    }
+}
@@ -0,0 +1,11 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day4.txt`.
+
+#[test]
+fn test_day4_example_dayfour() {
+    aoc_common::assert_example::<day_4::DayFour>();
+}
+
+#[test]
+fn test_day4_example_dayfournom() {
+    aoc_common::assert_example::<day_4::DayFourNom>();
+}
@@ -0,0 +1,46 @@
+//! Invariants of the overlap counts on generated assignment lists.
+
+mod strategies;
+
+use day_4::{handwritten, range_contains, range_intersects, with_nom};
+use proptest::prelude::*;
+use strategies::{pairs, render, sections};
+
+proptest! {
+    #[test]
+    fn test_day4_contains_implies_intersects(a in sections(), b in sections()) {
+        prop_assert!(!range_contains(&a, &b) || range_intersects(&a, &b));
+    }
+
+    #[test]
+    fn test_day4_checks_are_symmetric(a in sections(), b in sections()) {
+        prop_assert_eq!(range_contains(&a, &b), range_contains(&b, &a));
+        prop_assert_eq!(range_intersects(&a, &b), range_intersects(&b, &a));
+    }
+
+    #[test]
+    fn test_day4_a_range_contains_itself(a in sections()) {
+        prop_assert!(range_contains(&a, &a));
+    }
+
+    #[test]
+    fn test_day4_contained_pairs_are_overlapping_pairs(pairs in pairs()) {
+        let input = render(&pairs, false);
+        let contained = handwritten::part_one(&input).unwrap();
+        prop_assert!(contained <= handwritten::part_two(&input).unwrap());
+        prop_assert!(contained <= pairs.len());
+    }
+
+    #[test]
+    fn test_day4_nom_parses_every_pair(pairs in pairs(), trailing in any::<bool>()) {
+        prop_assert_eq!(with_nom::parse(&render(&pairs, trailing)).unwrap(), pairs);
+    }
+
+    #[test]
+    fn test_day4_swapping_elves_does_not_matter(pairs in pairs()) {
+        let swapped: Vec<_> = pairs.iter().cloned().map(|(a, b)| (b, a)).collect();
+        let (before, after) = (with_nom::parse(&render(&pairs, false)).unwrap(), with_nom::parse(&render(&swapped, false)).unwrap());
+        prop_assert_eq!(with_nom::part_one(&before), with_nom::part_one(&after));
+        prop_assert_eq!(with_nom::part_two(&before), with_nom::part_two(&after));
+    }
+}
@@ -0,0 +1,28 @@
+//! Generators of valid section assignment lists.
+
+use std::ops::RangeInclusive;
+
+use proptest::prelude::*;
+
+/// Sections of one elf, numbered up to 99 like the puzzle's.
+pub fn sections() -> impl Strategy<Value = RangeInclusive<u16>> {
+    (0..100u16, 0..100u16).prop_map(|(a, b)| a.min(b)..=a.max(b))
+}
+
+/// Assignments of each pair of elves.
+pub fn pairs() -> impl Strategy<Value = Vec<(RangeInclusive<u16>, RangeInclusive<u16>)>> {
+    prop::collection::vec((sections(), sections()), 0..40)
+}
+
+/// The list as the puzzle writes it, `<start>-<end>,<start>-<end>` per line,
+/// with or without a final newline.
+pub fn render(pairs: &[(RangeInclusive<u16>, RangeInclusive<u16>)], trailing: bool) -> String {
+    let mut input: Vec<String> = pairs
+        .iter()
+        .map(|(a, b)| format!("{}-{},{}-{}", a.start(), a.end(), b.start(), b.end()))
+        .collect();
+    if trailing {
+        input.push(String::new());
+    }
+    input.join("\n")
+}
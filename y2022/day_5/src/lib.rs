@@ -0,0 +1,335 @@
+//! Day 5: Supply Stacks.
+//!
+//! The input draws stacks of lettered crates, followed by moves that shift
+//! crates between stacks, one at a time in part one and all at once in part two.
+
+use aoc_common::{ensure_consumed, Error, Result, Solution};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{self, alpha1, digit1, multispace1, newline, space1};
+use nom::combinator::verify;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, preceded};
+use nom::IResult;
+
+/// Nom parsing taken from Chris Biscardi
+/// <https://github.com/ChristopherBiscardi/advent-of-code/blob/main/2022/rust/day-05/src/lib.rs>
+fn parse_crate(input: &str) -> IResult<&str, Option<&str>> {
+    let (input, c) = alt((
+        tag("   "),
+        delimited(complete::char('['), alpha1, complete::char(']')),
+    ))(input)?;
+
+    match c {
+        "   " => Ok((input, None)),
+        value => Ok((input, Some(value))),
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<Option<&str>>> {
+    let (input, result) = separated_list1(tag(" "), parse_crate)(input)?;
+    Ok((input, result))
+}
+
+/// Stacks of crates, bottom first, indexed from zero.
+pub type Tower<'a> = Vec<Vec<&'a str>>;
+
+/// Moves `count` crates from one stack to another, both indexed from zero.
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+const MOVE_FORMAT: &str = "expected 'move <count> from <stack> to <stack>'";
+
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    let (input, _) = tag("move ")(input)?;
+    let (input, count) = complete::u8(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    // Stacks are numbered from one.
+    let (input, from) = verify(complete::u8, |&n| n > 0)(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, to) = verify(complete::u8, |&n| n > 0)(input)?;
+    Ok((
+        input,
+        Move {
+            count: count as usize,
+            from: (from - 1) as usize,
+            to: (to - 1) as usize,
+        },
+    ))
+}
+
+fn parser_main(input: &str) -> IResult<&str, (Tower<'_>, Vec<Move>)> {
+    let (input, crates_horizontal) = separated_list1(newline, parse_line)(input)?;
+    let (input, _) = newline(input)?;
+    let (input, _numbers) = many1(preceded(space1, digit1))(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, moves) = separated_list1(newline, parse_move)(input)?;
+
+    // One stack per column of the widest row, however few rows there are.
+    let width = crates_horizontal.iter().map(Vec::len).max().unwrap_or(0);
+    let mut crates_vertical: Vec<Vec<Option<&str>>> = vec![vec![]; width];
+
+    for vec in crates_horizontal.iter().rev() {
+        for (i, c) in vec.iter().enumerate() {
+            crates_vertical[i].push(*c)
+        }
+    }
+
+    let final_crates: Tower<'_> = crates_vertical
+        .iter()
+        .map(|vec| vec.iter().filter_map(|v| *v).collect())
+        .collect();
+
+    Ok((input, (final_crates, moves)))
+}
+
+/// Parses the crate diagram and the list of moves.
+pub fn parse(input: &str) -> Result<(Tower<'_>, Vec<Move>)> {
+    let (input_rem, parsed) = parser_main(input)
+        .map_err(|err| Error::from_nom(input, err, "expected a crate diagram followed by moves"))?;
+    ensure_consumed(input, input_rem, parse_move, MOVE_FORMAT)?;
+    Ok(parsed)
+}
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const NAME: &'static str = "Day Five";
+
+    type Parsed<'a> = (Tower<'a>, Vec<Move>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse(input)
+    }
+
+    fn part_one((tower, moves): &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(&mut tower.clone(), moves)
+    }
+
+    fn part_two((tower, moves): &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(&mut tower.clone(), moves)
+    }
+}
+
+/// Takes the crates of the `number`th move off the top of its stack, top crate
+/// last, once the move is known to be possible.
+fn lift<'a>(tower: &mut Tower<'a>, number: usize, mv: &Move) -> Result<Vec<&'a str>> {
+    let stacks = tower.len();
+    let missing = |stack: usize| {
+        Error::invalid(format!(
+            "move {number}: no stack {}, there are {stacks}",
+            stack + 1
+        ))
+    };
+    if mv.to >= stacks {
+        return Err(missing(mv.to));
+    }
+    let stack = tower.get_mut(mv.from).ok_or_else(|| missing(mv.from))?;
+    let end = stack.len().checked_sub(mv.count).ok_or_else(|| {
+        Error::invalid(format!(
+            "move {number}: stack {} holds {} crates, not {}",
+            mv.from + 1,
+            stack.len(),
+            mv.count
+        ))
+    })?;
+    Ok(stack.drain(end..).collect())
+}
+
+/// Top crates after moving crates one at a time.
+pub fn part_one(tower: &mut Tower<'_>, moves: &[Move]) -> Result<String> {
+    for (number, mv) in (1..).zip(moves) {
+        let mut tmp = lift(tower, number, mv)?;
+        tmp.reverse();
+        tower[mv.to].append(&mut tmp);
+    }
+
+    let output: String = tower
+        .iter()
+        .map(|v| match v.iter().last() {
+            Some(c) => c,
+            None => "",
+        })
+        .collect();
+    Ok(output)
+}
+
+/// Top crates after moving each group of crates at once.
+pub fn part_two(tower: &mut Tower<'_>, moves: &[Move]) -> Result<String> {
+    for (number, mv) in (1..).zip(moves) {
+        let mut tmp = lift(tower, number, mv)?;
+        tower[mv.to].append(&mut tmp);
+    }
+
+    let output: String = tower
+        .iter()
+        .map(|v| match v.iter().last() {
+            Some(c) => c,
+            None => "",
+        })
+        .collect();
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::local_input;
+
+    const SAMPLE_INPUT: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn test_day5_input_parser_sample() {
+        let (input_rem, (tower, moves)) = parser_main(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(input_rem, "");
+
+        assert_eq!(tower[0], vec!["Z", "N"]);
+        assert_eq!(tower[1], vec!["M", "C", "D"]);
+        assert_eq!(tower[2], vec!["P"]);
+
+        let mv_a = Move {
+            count: 1,
+            from: 1,
+            to: 0,
+        };
+        assert_eq!(moves[0], mv_a, "testing mv_a");
+
+        let mv_b = Move {
+            count: 3,
+            from: 0,
+            to: 2,
+        };
+        assert_eq!(moves[1], mv_b, "testing mv_b");
+
+        let mv_c = Move {
+            count: 2,
+            from: 1,
+            to: 0,
+        };
+        assert_eq!(moves[2], mv_c, "testing mv_c");
+
+        let mv_d = Move {
+            count: 1,
+            from: 0,
+            to: 1,
+        };
+        assert_eq!(moves[3], mv_d, "testing mv_d");
+    }
+
+    #[test]
+    fn test_day5_part1_sample() {
+        let (_input_rem, (mut tower, moves)) = parser_main(SAMPLE_INPUT).unwrap();
+        let test_output = part_one(&mut tower, &moves).unwrap();
+
+        assert_eq!(tower[0], vec!["C"]);
+        assert_eq!(tower[1], vec!["M"]);
+        assert_eq!(tower[2], vec!["P", "D", "N", "Z"]);
+
+        assert_eq!(test_output, "CMZ".to_string());
+    }
+
+    #[test]
+    fn test_day5_part2_sample() {
+        let (_input_rem, (mut tower, moves)) = parser_main(SAMPLE_INPUT).unwrap();
+        let test_output = part_two(&mut tower, &moves).unwrap();
+
+        assert_eq!(tower[0], vec!["M"]);
+        assert_eq!(tower[1], vec!["C"]);
+        assert_eq!(tower[2], vec!["P", "Z", "N", "D"]);
+
+        assert_eq!(test_output, "MCD".to_string());
+    }
+
+    #[test]
+    fn test_day5_more_stacks_than_rows() {
+        let input = "[A]         [B]\n 1   2   3   4 \n\nmove 1 from 4 to 2";
+        let (tower, moves) = parse(input).unwrap();
+
+        assert_eq!(tower, vec![vec!["A"], vec![], vec![], vec!["B"]]);
+        assert_eq!(part_one(&mut tower.clone(), &moves).unwrap(), "AB");
+    }
+
+    /// Found by the `day_5` and `day_5_moves` fuzz targets: stack 0 used to
+    /// underflow, and missing stacks or crates to index out of bounds.
+    #[test]
+    fn test_day5_impossible_moves() {
+        let err = parse(&SAMPLE_INPUT.replace("from 1 to 3", "from 0 to 3")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 13: expected 'move <count> from <stack> to <stack>'"
+        );
+
+        for (mv, message) in [
+            ("move 1 from 4 to 1", "move 1: no stack 4, there are 3"),
+            ("move 1 from 1 to 9", "move 1: no stack 9, there are 3"),
+            (
+                "move 3 from 1 to 2",
+                "move 1: stack 1 holds 2 crates, not 3",
+            ),
+        ] {
+            let input = SAMPLE_INPUT.replace("move 1 from 2 to 1", mv);
+            let (mut tower, moves) = parse(&input).unwrap();
+            assert_eq!(
+                part_one(&mut tower.clone(), &moves)
+                    .unwrap_err()
+                    .to_string(),
+                message
+            );
+            assert_eq!(
+                part_two(&mut tower, &moves).unwrap_err().to_string(),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_day5_parse_errors() {
+        let input = SAMPLE_INPUT.replace("move 2 from 2 to 1", "move 2 from 2 onto 1");
+        let err = parse(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 8, column 14: expected 'move <count> from <stack> to <stack>'"
+        );
+
+        let err = parse("[A] [B]\n 1   2 \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a crate diagram followed by moves"
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day5.txt"]
+    fn test_day5_part1_actual() {
+        let input = local_input(2022, 5).unwrap();
+        let (_input_rem, (mut tower, moves)) = parser_main(&input).unwrap();
+        let test_output = part_one(&mut tower, &moves).unwrap();
+        assert_eq!(test_output, "VJSFHWGFT".to_string());
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day5.txt"]
+    fn test_day5_part2_actual() {
+        let input = local_input(2022, 5).unwrap();
+        let (_input_rem, (mut tower, moves)) = parser_main(&input).unwrap();
+        let test_output = part_two(&mut tower, &moves).unwrap();
+        assert_eq!(test_output, "LCTQFBVZV".to_string());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_5::DayFive;
+
+fn main() {
+    run::<DayFive>();
+}
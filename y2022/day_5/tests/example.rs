@@ -0,0 +1,6 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day5.txt`.
+
+#[test]
+fn test_day5_example() {
+    aoc_common::assert_example::<day_5::DayFive>();
+}
@@ -0,0 +1,60 @@
+//! Invariants of the crane on generated diagrams and legal moves.
+
+mod strategies;
+
+use day_5::{parse, part_one, part_two, Tower};
+use proptest::prelude::*;
+use strategies::{render, single_crate_supplies, supplies};
+
+/// Every crate in the tower, sorted.
+fn sorted_crates(tower: &Tower<'_>) -> Vec<String> {
+    let mut crates: Vec<String> = tower.iter().flatten().map(|c| c.to_string()).collect();
+    crates.sort();
+    crates
+}
+
+proptest! {
+    #[test]
+    fn test_day5_parse_reads_back_the_diagram(supplies in supplies()) {
+        let input = render(&supplies);
+        let (tower, moves) = parse(&input).unwrap();
+
+        for (i, stack) in supplies.stacks.iter().enumerate() {
+            let expected: Vec<String> = stack.iter().map(char::to_string).collect();
+            prop_assert_eq!(&tower[i], &expected);
+        }
+        prop_assert!(tower[supplies.stacks.len()..].iter().all(Vec::is_empty));
+        let read: Vec<(usize, usize, usize)> = moves
+            .iter()
+            .map(|m| (m.count, m.from + 1, m.to + 1))
+            .collect();
+        prop_assert_eq!(read, supplies.moves);
+    }
+
+    #[test]
+    fn test_day5_moves_keep_every_crate(supplies in supplies()) {
+        let input = render(&supplies);
+        let (tower, moves) = parse(&input).unwrap();
+        let before = sorted_crates(&tower);
+        prop_assert_eq!(before.len(), supplies.crates());
+
+        for part in [part_one, part_two] {
+            let mut moved = tower.clone();
+            let tops = part(&mut moved, &moves).unwrap();
+            prop_assert_eq!(&sorted_crates(&moved), &before);
+            prop_assert_eq!(tops.len(), moved.iter().filter(|stack| !stack.is_empty()).count());
+        }
+    }
+
+    #[test]
+    fn test_day5_single_crate_moves_are_the_same_for_both_cranes(
+        supplies in single_crate_supplies()
+    ) {
+        let input = render(&supplies);
+        let (tower, moves) = parse(&input).unwrap();
+        prop_assert_eq!(
+            part_one(&mut tower.clone(), &moves).unwrap(),
+            part_two(&mut tower.clone(), &moves).unwrap()
+        );
+    }
+}
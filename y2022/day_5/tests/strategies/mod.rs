@@ -0,0 +1,97 @@
+//! Generators of crate diagrams and moves that only ever take crates a stack
+//! has.
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// Stacks of crates, bottom first, and moves as the puzzle numbers them:
+/// `(count, from, to)` with stacks counted from one.
+#[derive(Debug, Clone)]
+pub struct Supplies {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<(usize, usize, usize)>,
+}
+
+impl Supplies {
+    pub fn crates(&self) -> usize {
+        self.stacks.iter().map(Vec::len).sum()
+    }
+}
+
+/// Up to nine stacks, holding at least one crate between them.
+fn stacks() -> impl Strategy<Value = Vec<Vec<char>>> {
+    let stack = prop::collection::vec(prop::char::range('A', 'Z'), 0..8);
+    prop::collection::vec(stack, 1..=9).prop_filter("no crates to move", |stacks| {
+        stacks.iter().any(|stack| !stack.is_empty())
+    })
+}
+
+/// Moves chosen by `picks`, each taking at most `most` crates and
+/// never more than its stack holds at that point.
+fn legal_moves(
+    stacks: &[Vec<char>],
+    picks: Vec<(Index, Index, Index)>,
+    most: usize,
+) -> Vec<(usize, usize, usize)> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    picks
+        .into_iter()
+        .map(|(from, count, to)| {
+            let loaded: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+            let from = loaded[from.index(loaded.len())];
+            let count = 1 + count.index(heights[from].min(most));
+            let to = to.index(heights.len());
+            heights[from] -= count;
+            heights[to] += count;
+            (count, from + 1, to + 1)
+        })
+        .collect()
+}
+
+fn supplies_moving(most: usize) -> impl Strategy<Value = Supplies> {
+    let picks = prop::collection::vec(any::<(Index, Index, Index)>(), 1..20);
+    (stacks(), picks).prop_map(move |(stacks, picks)| Supplies {
+        moves: legal_moves(&stacks, picks, most),
+        stacks,
+    })
+}
+
+/// Diagrams followed by legal moves of any size.
+pub fn supplies() -> impl Strategy<Value = Supplies> {
+    supplies_moving(usize::MAX)
+}
+
+/// Diagrams followed by legal moves of one crate each.
+pub fn single_crate_supplies() -> impl Strategy<Value = Supplies> {
+    supplies_moving(1)
+}
+
+/// The drawing and moves as the puzzle writes them, rows padded to the full
+/// width of the diagram.
+pub fn render(supplies: &Supplies) -> String {
+    let height = supplies.stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|row| {
+            let cells: Vec<String> = supplies
+                .stacks
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(row)
+                        .map_or("   ".to_string(), |c| format!("[{c}]"))
+                })
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    let numbers: Vec<String> = (1..=supplies.stacks.len())
+        .map(|i| format!(" {i} "))
+        .collect();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+    for (count, from, to) in &supplies.moves {
+        lines.push(format!("move {count} from {from} to {to}"));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,217 @@
+//! Day 6: Tuning Trouble.
+//!
+//! The input is a stream of lowercase letters; a marker ends after a run of
+//! distinct letters, four for a packet and fourteen for a message.
+
+use std::str;
+
+use aoc_common::{Error, Result, Solution};
+
+/// Bit offset approach from Amos (fasterthanlime)
+/// <https://fasterthanli.me/series/advent-of-code-2022/part-6>
+///
+/// Maps a lowercase letter to its own bit, so a set of letters fits in a `u32`;
+/// anything else has no bit.
+pub trait GetBitOffset {
+    fn get_bit_offset(&self) -> Option<u32>;
+}
+
+impl GetBitOffset for u8 {
+    fn get_bit_offset(&self) -> Option<u32> {
+        self.is_ascii_lowercase().then(|| 1 << (self - b'a'))
+    }
+}
+
+/// Checks the stream only holds lowercase letters.
+pub fn parse(input: &str) -> Result<&str> {
+    match input.find(|c: char| !c.is_ascii_lowercase()) {
+        Some(idx) => Err(Error::parse(
+            input,
+            &input[idx..],
+            "expected a lowercase letter",
+        )),
+        None => Ok(input),
+    }
+}
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const NAME: &'static str = "Day Six";
+
+    type Parsed<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Parsed<'_>) -> Result<Self::PartOne> {
+        part_one(input).ok_or_else(|| Error::no_answer("no start-of-packet marker found"))
+    }
+
+    fn part_two(input: &Self::Parsed<'_>) -> Result<Self::PartTwo> {
+        part_two(input).ok_or_else(|| Error::no_answer("no start-of-message marker found"))
+    }
+}
+
+/// Start-of-packet marker found by rolling four bit registers by hand, `None`
+/// if a byte before it is not a lowercase letter.
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut input_iter = input.as_bytes().iter();
+    let (Some(a), Some(b), Some(c)) = (input_iter.next(), input_iter.next(), input_iter.next())
+    else {
+        return None;
+    };
+    let mut a = a.get_bit_offset()?;
+    let mut b = b.get_bit_offset()?;
+    let mut c = c.get_bit_offset()?;
+
+    for (index, val) in (4..).zip(input_iter) {
+        let d = val.get_bit_offset()?;
+        let comp = a | b | c | d;
+        if comp.count_ones() == 4 {
+            return Some(index);
+        }
+
+        a = b;
+        b = c;
+        c = d;
+    }
+    None
+}
+
+/// Start-of-message marker, a window of 14 distinct characters.
+pub fn part_two(input: &str) -> Option<u32> {
+    find_marker(input, 14)
+}
+
+/// Position just past the first `len` distinct characters, using `windows`;
+/// `None` if a byte before them is not a lowercase letter.
+pub fn find_marker(input: &str, len: usize) -> Option<u32> {
+    if len == 0 {
+        return Some(0);
+    }
+    for (idx, window) in input.as_bytes().windows(len).enumerate() {
+        let seen = window
+            .iter()
+            .try_fold(0, |acc, c| Some(acc | c.get_bit_offset()?))?;
+        if seen.count_ones() == len as u32 {
+            return Some((idx + len) as u32);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::local_input;
+
+    const SAMPLE_A: (&str, u32, u32) = ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23);
+    const SAMPLE_B: (&str, u32, u32) = ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23);
+    const SAMPLE_C: (&str, u32, u32) = ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29);
+    const SAMPLE_D: (&str, u32, u32) = ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26);
+    const SAMPLE_E: (&str, u32, u32) = ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19);
+
+    #[test]
+    fn test_day6_part1_sample() {
+        let sample_res = part_one(SAMPLE_A.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_A.1,);
+
+        let sample_res = part_one(SAMPLE_B.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_B.1,);
+
+        let sample_res = part_one(SAMPLE_C.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_C.1,);
+
+        let sample_res = part_one(SAMPLE_D.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_D.1,);
+
+        let sample_res = part_one(SAMPLE_E.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_E.1,);
+    }
+
+    #[test]
+    fn test_day6_part2_sample() {
+        let sample_res = part_two(SAMPLE_A.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_A.2,);
+
+        let sample_res = part_two(SAMPLE_B.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_B.2,);
+
+        let sample_res = part_two(SAMPLE_C.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_C.2,);
+
+        let sample_res = part_two(SAMPLE_D.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_D.2,);
+
+        let sample_res = part_two(SAMPLE_E.0).unwrap();
+        assert_eq!(sample_res, SAMPLE_E.2,);
+    }
+
+    #[test]
+    fn test_day6_find_marker_matches_part_one() {
+        for sample in [SAMPLE_A, SAMPLE_B, SAMPLE_C, SAMPLE_D, SAMPLE_E] {
+            assert_eq!(find_marker(sample.0, 4), part_one(sample.0));
+        }
+    }
+
+    #[test]
+    fn test_day6_errors() {
+        let err = DaySix::parse("mjqjpq\nmgbl").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: expected a lowercase letter"
+        );
+
+        let err = DaySix::part_one(&"abab").unwrap_err();
+        assert_eq!(err.to_string(), "no start-of-packet marker found");
+        assert_eq!(part_one("ab"), None);
+    }
+
+    /// Found by the `day_6` fuzz target, `windows(0)` used to panic.
+    #[test]
+    fn test_day6_find_marker_lengths() {
+        assert_eq!(find_marker(SAMPLE_E.0, 0), Some(0));
+        assert_eq!(find_marker(SAMPLE_E.0, 1), Some(1));
+        assert_eq!(find_marker(SAMPLE_E.0, 27), None);
+    }
+
+    /// Found by the `day_6` fuzz target once it fed raw input to the parts:
+    /// anything but a lowercase letter used to overflow the shift.
+    #[test]
+    fn test_day6_unparsed_input() {
+        for input in [
+            "ABCDEFG",
+            "abc{defghijklmnopq",
+            "ab\ncdefghijklmnopq",
+            "éabcdefghijklmnopq",
+        ] {
+            assert_eq!(part_one(input), None, "{input:?}");
+            assert_eq!(part_two(input), None, "{input:?}");
+            assert_eq!(find_marker(input, 4), None, "{input:?}");
+        }
+        assert_eq!(part_one("abcdE"), Some(4));
+        assert_eq!(find_marker("abcdE", 4), Some(4));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day6.txt"]
+    fn test_day6_part1_actual() {
+        let input = local_input(2022, 6).unwrap();
+        let actual_res = part_one(&input).unwrap();
+        assert_eq!(actual_res, 1361);
+    }
+
+    #[test]
+    #[ignore = "needs inputs/2022/day6.txt"]
+    fn test_day6_part2_actual() {
+        let input = local_input(2022, 6).unwrap();
+        let actual_res = part_two(&input).unwrap();
+        assert_eq!(actual_res, 3263);
+    }
+}
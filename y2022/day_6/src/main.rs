@@ -0,0 +1,6 @@
+use aoc_common::run;
+use day_6::DaySix;
+
+fn main() {
+    run::<DaySix>();
+}
@@ -0,0 +1,18 @@
+//! Cross-checks the rolling registers of `part_one` against the generic window
+//! search; proptest shrinks any disagreement down to a minimal stream.
+
+use day_6::{find_marker, part_one};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_day6_part_one_matches_find_marker(input in "[a-z]{0,60}") {
+        prop_assert_eq!(part_one(&input), find_marker(&input, 4));
+    }
+
+    /// Few letters make repeats likely, so markers come late or not at all.
+    #[test]
+    fn test_day6_part_one_matches_find_marker_few_letters(input in "[a-e]{0,30}") {
+        prop_assert_eq!(part_one(&input), find_marker(&input, 4));
+    }
+}
@@ -0,0 +1,6 @@
+//! Generated by `aoc scrape`, checks the puzzle's example from `examples/2022/day6.txt`.
+
+#[test]
+fn test_day6_example() {
+    aoc_common::assert_example::<day_6::DaySix>();
+}
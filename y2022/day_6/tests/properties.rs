@@ -0,0 +1,33 @@
+//! Invariants of the marker search on generated signal streams.
+
+mod strategies;
+
+use day_6::{find_marker, part_one, part_two};
+use proptest::prelude::*;
+use strategies::planted;
+
+proptest! {
+    #[test]
+    fn test_day6_part_one_finds_the_planted_packet(planted in planted(4)) {
+        prop_assert_eq!(part_one(&planted.stream), Some(planted.marker));
+    }
+
+    #[test]
+    fn test_day6_part_two_finds_the_planted_message(planted in planted(14)) {
+        prop_assert_eq!(part_two(&planted.stream), Some(planted.marker));
+    }
+
+    #[test]
+    fn test_day6_find_marker_finds_any_length(
+        (len, planted) in (2..=26usize).prop_flat_map(|len| (Just(len), planted(len)))
+    ) {
+        prop_assert_eq!(find_marker(&planted.stream, len), Some(planted.marker));
+    }
+
+    /// A message marker holds packet markers, the first ending ten letters in.
+    #[test]
+    fn test_day6_packet_comes_well_before_message(planted in planted(14)) {
+        let packet = part_one(&planted.stream).unwrap();
+        prop_assert!(packet + 10 <= planted.marker);
+    }
+}
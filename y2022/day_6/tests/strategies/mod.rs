@@ -0,0 +1,32 @@
+//! Generators of signal streams with a marker planted at a known position.
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// A stream whose first run of `len` distinct letters ends at `marker`.
+#[derive(Debug, Clone)]
+pub struct Planted {
+    pub stream: String,
+    pub marker: u32,
+}
+
+/// Streams that repeat `len - 1` letters for a while, play them once more
+/// in some order and then add a new one, which completes the first marker;
+/// anything may follow. `len` is at least two.
+pub fn planted(len: usize) -> impl Strategy<Value = Planted> {
+    let letters: Vec<char> = ('a'..='z').collect();
+    (
+        Just(letters).prop_shuffle(),
+        prop::collection::vec(any::<Index>(), 0..40),
+        "[a-z]{0,20}",
+    )
+        .prop_map(move |(letters, picks, rest)| {
+            let (repeated, new) = (&letters[..len - 1], letters[len - 1]);
+            let mut stream: String = picks.iter().map(|pick| *pick.get(repeated)).collect();
+            stream.extend(repeated);
+            stream.push(new);
+            let marker = stream.len() as u32;
+            stream.push_str(&rest);
+            Planted { stream, marker }
+        })
+}